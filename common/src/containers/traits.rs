@@ -26,3 +26,9 @@ pub trait SearchableContainer<T> {
     where
         F: Fn(&T) -> bool;
 }
+
+pub trait IterableContainer<T> {
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
+}
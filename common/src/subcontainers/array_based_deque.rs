@@ -1,5 +1,5 @@
 use crate::containers::traits::{
-    ErasableContainer, FillableContainer, SearchableContainer, SizedContainer,
+    ErasableContainer, FillableContainer, IterableContainer, SearchableContainer, SizedContainer,
 };
 use crate::subcontainers::resizable_array::ResizableArray;
 use crate::subcontainers::traits::*;
@@ -56,7 +56,60 @@ where
     }
 
     fn double_the_capacity(&mut self) {
-        self.ring.double_the_size();
+        let mut new_ring = ResizableArray::new(self.capacity() * 2);
+
+        for i in 0..self.size {
+            let ndx = (self.head + i) & (self.capacity() - 1);
+            new_ring[i] = std::mem::take(&mut self.ring[ndx]);
+        }
+
+        self.ring = new_ring;
+        self.head = 0;
+        self.tail = self.prev_ndx(self.size);
+    }
+
+    fn live_slices(&self) -> (&[T], &[T]) {
+        let ring = self.ring.as_slice();
+
+        if self.head + self.size <= ring.len() {
+            (&ring[self.head..self.head + self.size], &[])
+        } else {
+            let (wrapped, front) = ring.split_at(self.head);
+            (front, &wrapped[..self.head + self.size - ring.len()])
+        }
+    }
+
+    fn live_slices_mut(&mut self) -> (&mut [T], &mut [T]) {
+        let (head, size) = (self.head, self.size);
+        let ring = self.ring.as_mut_slice();
+        let capacity = ring.len();
+
+        if head + size <= capacity {
+            (&mut ring[head..head + size], &mut [])
+        } else {
+            let (wrapped, front) = ring.split_at_mut(head);
+            (front, &mut wrapped[..head + size - capacity])
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let (front, wrapped) = self.live_slices_mut();
+        front.iter_mut().chain(wrapped.iter_mut())
+    }
+
+    fn remove_at(&mut self, position: usize) -> T {
+        let mut ndx = (self.head + position) & (self.capacity() - 1);
+        let removed = std::mem::take(&mut self.ring[ndx]);
+
+        for _ in position + 1..self.size {
+            let next = self.next_ndx(ndx);
+            self.ring[ndx] = std::mem::take(&mut self.ring[next]);
+            ndx = next;
+        }
+
+        self.tail = self.prev_ndx(self.tail);
+        self.size -= 1;
+        removed
     }
 
     fn capacity(&self) -> usize {
@@ -144,7 +197,8 @@ where
     where
         F: Fn(&T) -> bool,
     {
-        self.find_mut(predicate).map(|found| std::mem::take(found))
+        let position = self.iter().position(predicate)?;
+        Some(self.remove_at(position))
     }
 }
 
@@ -166,14 +220,27 @@ where
     where
         F: Fn(&T) -> bool,
     {
-        self.ring.find(predicate)
+        self.iter().find(|element| predicate(element))
     }
 
     fn find_mut<F>(&mut self, predicate: F) -> Option<&mut T>
     where
         F: Fn(&T) -> bool,
     {
-        self.ring.find_mut(predicate)
+        self.iter_mut().find(|element| predicate(element))
+    }
+}
+
+impl<T> IterableContainer<T> for ArrayBasedDeque<T>
+where
+    T: Default,
+{
+    fn iter<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        let (front, wrapped) = self.live_slices();
+        front.iter().chain(wrapped.iter())
     }
 }
 
@@ -231,4 +298,40 @@ mod tests {
         assert!(deque.front().is_none());
         assert_eq!(deque.size(), 0);
     }
+
+    #[test]
+    fn erase_and_iterate_test() {
+        let mut deque = ArrayBasedDeque::new();
+
+        for i in 0..4 {
+            deque.push_front(i);
+        }
+
+        for i in 4..10 {
+            deque.push_back(i);
+        }
+
+        assert_eq!(
+            deque.iter().copied().collect::<Vec<_>>(),
+            vec![3, 2, 1, 0, 4, 5, 6, 7, 8, 9]
+        );
+
+        assert_eq!(deque.erase_first(|&x| x == 0), Some(0));
+        assert_eq!(deque.erase_first(|&x| x == 3), Some(3));
+        assert_eq!(deque.erase_first(|&x| x == 9), Some(9));
+        assert_eq!(deque.erase_first(|&x| x == 100), None);
+        assert_eq!(deque.size(), 7);
+        assert_eq!(deque.find(|&x| x == 0), None);
+        assert_eq!(
+            deque.iter().copied().collect::<Vec<_>>(),
+            vec![2, 1, 4, 5, 6, 7, 8]
+        );
+
+        deque.push(10);
+        assert_eq!(deque.back(), Some(&10));
+        assert_eq!(deque.front(), Some(&2));
+
+        while deque.pop_front().is_some() {}
+        assert_eq!(deque.iter().count(), 0);
+    }
 }
@@ -1,6 +1,9 @@
 use crate::containers::traits::SizedContainer;
 use crate::subcontainers::traits::{BackOrientedContainer, Deque, FrontOrientedContainer};
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_OWNER_ID: AtomicUsize = AtomicUsize::new(1);
 
 struct Node<T> {
     element: T,
@@ -8,7 +11,31 @@ struct Node<T> {
     prev_node: Option<Rc<Node<T>>>,
 }
 
+pub struct NodeHandle<T> {
+    owner_id: usize,
+    node: Weak<Node<T>>,
+}
+
+impl<T> Default for NodeHandle<T> {
+    fn default() -> Self {
+        Self {
+            owner_id: 0,
+            node: Weak::new(),
+        }
+    }
+}
+
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            owner_id: self.owner_id,
+            node: self.node.clone(),
+        }
+    }
+}
+
 pub struct ListBasedDeque<T> {
+    owner_id: usize,
     size: usize,
     head: Option<Rc<Node<T>>>,
     tail: Option<Rc<Node<T>>>,
@@ -19,6 +46,47 @@ impl<T> ListBasedDeque<T> {
         Default::default()
     }
 
+    pub fn push_back_with_handle(&mut self, element: T) -> NodeHandle<T> {
+        self.push_back(element);
+        NodeHandle {
+            owner_id: self.owner_id,
+            node: Rc::downgrade(self.tail.as_ref().unwrap()),
+        }
+    }
+
+    pub fn remove(&mut self, handle: &NodeHandle<T>) -> Option<T> {
+        if handle.owner_id != self.owner_id {
+            return None;
+        }
+
+        let node = handle.node.upgrade()?;
+
+        let (prev, next) = match (node.prev_node.clone(), node.next_node.clone()) {
+            (None, _) => {
+                drop(node);
+                return self.pop_front();
+            }
+            (_, None) => {
+                drop(node);
+                return self.pop_back();
+            }
+            (Some(prev), Some(next)) => (prev, next),
+        };
+
+        unsafe {
+            Self::modify_node(&Some(prev.clone()), |prev_ptr| {
+                (*prev_ptr).next_node = Some(next.clone())
+            });
+            Self::modify_node(&Some(next.clone()), |next_ptr| {
+                (*next_ptr).prev_node = Some(prev.clone())
+            });
+        }
+
+        self.size -= 1;
+
+        Rc::try_unwrap(node).ok().map(|node| node.element)
+    }
+
     unsafe fn modify_node(node: &Option<Rc<Node<T>>>, mut f: impl FnMut(*mut Node<T>)) {
         if let Some(ref next) = node {
             let next_ptr = Rc::as_ptr(next) as *mut Node<T>;
@@ -30,6 +98,7 @@ impl<T> ListBasedDeque<T> {
 impl<T> Default for ListBasedDeque<T> {
     fn default() -> Self {
         Self {
+            owner_id: NEXT_OWNER_ID.fetch_add(1, Ordering::Relaxed),
             size: 0,
             head: None,
             tail: None,
@@ -37,6 +106,12 @@ impl<T> Default for ListBasedDeque<T> {
     }
 }
 
+impl<T> Drop for ListBasedDeque<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
 impl<T> SizedContainer for ListBasedDeque<T> {
     #[inline(always)]
     fn size(&self) -> usize {
@@ -165,4 +240,56 @@ mod tests {
             assert_eq!(deque.size(), size);
         }
     }
+
+    #[test]
+    fn remove_by_handle_test() {
+        let mut deque = ListBasedDeque::new();
+        let handles = (0..5)
+            .map(|i| deque.push_back_with_handle(i))
+            .collect::<Vec<_>>();
+
+        assert_eq!(deque.remove(&handles[2]), Some(2));
+        assert_eq!(deque.remove(&handles[2]), None);
+        assert_eq!(deque.remove(&handles[0]), Some(0));
+        assert_eq!(deque.remove(&handles[4]), Some(4));
+        assert_eq!(deque.size(), 2);
+        assert_eq!(deque.front(), Some(&1));
+        assert_eq!(deque.back(), Some(&3));
+
+        let handle = deque.push_back_with_handle(5);
+        assert_eq!(deque.remove(&handles[1]), Some(1));
+        assert_eq!(deque.remove(&handles[3]), Some(3));
+        assert_eq!(deque.front(), Some(&5));
+        assert_eq!(deque.remove(&handle), Some(5));
+        assert_eq!(deque.size(), 0);
+        assert!(deque.front().is_none());
+        assert!(deque.back().is_none());
+    }
+
+    #[test]
+    fn remove_foreign_handle_test() {
+        let mut deque = ListBasedDeque::new();
+        let mut other = ListBasedDeque::new();
+        let handles = (0..3)
+            .map(|i| deque.push_back_with_handle(i))
+            .collect::<Vec<_>>();
+        other.push_back(10);
+        let other_handle = other.push_back_with_handle(11);
+        other.push_back(12);
+
+        for handle in handles.iter() {
+            assert_eq!(other.remove(handle), None);
+        }
+        assert_eq!(deque.remove(&other_handle), None);
+        assert_eq!(deque.remove(&NodeHandle::default()), None);
+
+        assert_eq!(deque.size(), 3);
+        assert_eq!(other.size(), 3);
+        assert_eq!(other.front(), Some(&10));
+        assert_eq!(other.back(), Some(&12));
+        assert_eq!(other.remove(&other_handle), Some(11));
+        assert_eq!(deque.remove(&handles[1]), Some(1));
+        assert_eq!(deque.front(), Some(&0));
+        assert_eq!(deque.back(), Some(&2));
+    }
 }
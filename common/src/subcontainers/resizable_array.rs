@@ -45,6 +45,16 @@ where
         self.resize(self.size() * 2);
     }
 
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        &self.array
+    }
+
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.array
+    }
+
     fn allocate_slice<F>(size: usize, mut initializer: F) -> Box<[T]>
     where
         F: FnMut() -> T,
@@ -1,13 +1,27 @@
 use crate::pearson_map::{Pair, PearsonMap};
+use crate::pearson_multimap::PearsonMultiMap;
+use crate::pearson_set::PearsonSet;
 use common::subcontainers::array_based_deque::*;
 
+pub mod lru_cache;
 pub mod pearson_map;
+pub mod pearson_multimap;
+pub mod pearson_set;
+pub mod perfect_pearson_map;
+pub mod permutation;
+pub mod persistence;
+pub mod sharded_pearson_map;
 
 pub type PersonArrayMap<ValueType> = PearsonMap<ValueType, ArrayBasedDeque<Pair<ValueType>>>;
+pub type PearsonArraySet = PearsonSet<ArrayBasedDeque<Pair<()>>>;
+pub type PearsonArrayMultiMap<ValueType> =
+    PearsonMultiMap<ValueType, ArrayBasedDeque<Pair<Vec<ValueType>>>>;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::pearson_map::{pearson_hash, PERMUTATION};
+    use crate::permutation::generate_permutation;
 
     #[test]
     fn basic_hashmap_test() {
@@ -51,4 +65,107 @@ mod tests {
             assert_eq!(unwrap_option(hash_map.find(&format!("string{}", i))), i);
         }
     }
+
+    #[test]
+    fn get_and_iter_test() {
+        let mut hash_map = PersonArrayMap::<i32>::default();
+
+        for i in 0..128 {
+            hash_map.put(format!("string{}", i), i);
+        }
+
+        assert_eq!(hash_map.find(""), None);
+        assert_eq!(hash_map.get("string0"), Some(0));
+        assert_eq!(hash_map.find("string0"), None);
+        assert_eq!(hash_map.iter().count(), hash_map.size());
+        assert_eq!(hash_map.size(), 127);
+    }
+
+    #[test]
+    fn all_buckets_reachable_test() {
+        let mut single_byte_hashes = (0..=255u8)
+            .map(|byte| pearson_hash(&[byte]))
+            .collect::<Vec<_>>();
+        single_byte_hashes.sort();
+        single_byte_hashes.dedup();
+        assert_eq!(single_byte_hashes.len(), 256);
+
+        let mut hash_map = PersonArrayMap::<usize>::default();
+        let keys_count = 256 * 16;
+
+        for i in 0..keys_count {
+            hash_map.put(format!("key{}", i), i);
+        }
+
+        let bucket_sizes = hash_map.bucket_sizes().collect::<Vec<_>>();
+        assert_eq!(bucket_sizes.len(), 256);
+        assert_eq!(bucket_sizes.iter().sum::<usize>(), keys_count);
+        assert!(bucket_sizes.iter().all(|&size| size > 0));
+        assert!(bucket_sizes
+            .iter()
+            .all(|&size| size <= 3 * keys_count / 256));
+    }
+
+    #[test]
+    fn custom_permutation_test() {
+        let permutation = generate_permutation(7);
+        let mut hash_map = PersonArrayMap::<i32>::with_permutation(permutation);
+        assert_eq!(hash_map.permutation(), &permutation);
+
+        for i in 0..256 {
+            hash_map.put(format!("string{}", i), i);
+        }
+
+        for i in 0..256 {
+            assert_eq!(hash_map.find(&format!("string{}", i)), Some(&i));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn non_permutation_table_test() {
+        let mut table = PERMUTATION;
+        table[0] = table[1];
+        let _ = PersonArrayMap::<i32>::with_permutation(table);
+    }
+
+    fn colliding_keys(count: usize) -> Vec<String> {
+        (0..)
+            .map(|i| format!("attack{}", i))
+            .filter(|key| pearson_hash(key.as_bytes()) == 0)
+            .take(count)
+            .collect()
+    }
+
+    fn longest_chain(hash_map: &PersonArrayMap<usize>) -> usize {
+        hash_map.bucket_sizes().max().unwrap_or(0)
+    }
+
+    #[test]
+    fn hash_flooding_test() {
+        let keys = colliding_keys(512);
+
+        let mut unkeyed_map = PersonArrayMap::default();
+        for (i, key) in keys.iter().enumerate() {
+            unkeyed_map.put(key.clone(), i);
+        }
+        assert_eq!(longest_chain(&unkeyed_map), keys.len());
+
+        let mut keyed_maps = (0..8u64)
+            .map(|seed| PersonArrayMap::with_seed(seed * 0x9E37_79B9))
+            .chain(std::iter::once(PersonArrayMap::randomized()))
+            .collect::<Vec<_>>();
+
+        for hash_map in keyed_maps.iter_mut() {
+            for (i, key) in keys.iter().enumerate() {
+                hash_map.put(key.clone(), i);
+            }
+
+            for (i, key) in keys.iter().enumerate() {
+                assert_eq!(hash_map.find(key), Some(&i));
+            }
+
+            assert!(longest_chain(hash_map) <= 16);
+        }
+    }
 }
@@ -0,0 +1,323 @@
+use crate::pearson_map::{Pair, PearsonMap};
+use common::subcontainers::array_based_deque::ArrayBasedDeque;
+use common::subcontainers::list_based_deque::{ListBasedDeque, NodeHandle};
+use common::subcontainers::traits::FrontOrientedContainer;
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+#[derive(Default)]
+struct Entry<ValueType>
+where
+    ValueType: Default,
+{
+    value: ValueType,
+    handle: NodeHandle<String>,
+}
+
+type EntryMap<ValueType> = PearsonMap<Entry<ValueType>, ArrayBasedDeque<Pair<Entry<ValueType>>>>;
+
+pub struct LruCache<ValueType>
+where
+    ValueType: Default,
+{
+    entries: EntryMap<ValueType>,
+    recency: ListBasedDeque<String>,
+    capacity: usize,
+}
+
+impl<ValueType> LruCache<ValueType>
+where
+    ValueType: Default,
+{
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be positive");
+
+        Self {
+            entries: Default::default(),
+            recency: ListBasedDeque::new(),
+            capacity,
+        }
+    }
+
+    pub fn put(&mut self, key: String, value: ValueType) -> Option<(String, ValueType)> {
+        if let Some(entry) = self.entries.find_mut(&key) {
+            entry.value = value;
+            self.touch(&key);
+            return None;
+        }
+
+        let evicted = (self.entries.size() == self.capacity)
+            .then(|| self.pop_lru())
+            .flatten();
+
+        let handle = self.recency.push_back_with_handle(key.clone());
+        self.entries.put(key, Entry { value, handle });
+
+        evicted
+    }
+
+    pub fn get(&mut self, key: &str) -> Option<&ValueType> {
+        self.touch(key)?;
+        self.peek(key)
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut ValueType> {
+        self.touch(key)?;
+        self.entries.find_mut(key).map(|entry| &mut entry.value)
+    }
+
+    pub fn peek(&self, key: &str) -> Option<&ValueType> {
+        self.entries.find(key).map(|entry| &entry.value)
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.contains(key)
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<ValueType> {
+        let entry = self.entries.get(key)?;
+        self.recency.remove(&entry.handle);
+        Some(entry.value)
+    }
+
+    pub fn pop_lru(&mut self) -> Option<(String, ValueType)> {
+        let key = self.recency.pop_front()?;
+        let entry = self.entries.get(&key)?;
+        Some((key, entry.value))
+    }
+
+    pub fn size(&self) -> usize {
+        self.entries.size()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys()
+    }
+
+    fn touch(&mut self, key: &str) -> Option<()> {
+        let entry = self.entries.find_mut(key)?;
+        let key = self.recency.remove(&entry.handle)?;
+        entry.handle = self.recency.push_back_with_handle(key);
+        Some(())
+    }
+}
+
+pub trait Clock {
+    fn now(&self) -> Duration;
+}
+
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct ManualClock {
+    now: Rc<Cell<Duration>>,
+}
+
+impl ManualClock {
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+}
+
+#[derive(Default)]
+struct Expiring<ValueType>
+where
+    ValueType: Default,
+{
+    value: ValueType,
+    expires_at: Duration,
+}
+
+pub struct TtlCache<ValueType, C = SystemClock>
+where
+    ValueType: Default,
+    C: Clock,
+{
+    cache: LruCache<Expiring<ValueType>>,
+    clock: C,
+    default_ttl: Duration,
+}
+
+impl<ValueType> TtlCache<ValueType>
+where
+    ValueType: Default,
+{
+    pub fn new(capacity: usize, default_ttl: Duration) -> Self {
+        Self::with_clock(capacity, default_ttl, SystemClock::default())
+    }
+}
+
+impl<ValueType, C> TtlCache<ValueType, C>
+where
+    ValueType: Default,
+    C: Clock,
+{
+    pub fn with_clock(capacity: usize, default_ttl: Duration, clock: C) -> Self {
+        Self {
+            cache: LruCache::new(capacity),
+            clock,
+            default_ttl,
+        }
+    }
+
+    pub fn put(&mut self, key: String, value: ValueType) -> Option<(String, ValueType)> {
+        self.put_with_ttl(key, value, self.default_ttl)
+    }
+
+    pub fn put_with_ttl(
+        &mut self,
+        key: String,
+        value: ValueType,
+        ttl: Duration,
+    ) -> Option<(String, ValueType)> {
+        let expires_at = self.clock.now() + ttl;
+        self.cache
+            .put(key, Expiring { value, expires_at })
+            .map(|(key, expiring)| (key, expiring.value))
+    }
+
+    pub fn get(&mut self, key: &str) -> Option<&ValueType> {
+        self.evict_if_expired(key)?;
+        self.cache.get(key).map(|expiring| &expiring.value)
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut ValueType> {
+        self.evict_if_expired(key)?;
+        self.cache.get_mut(key).map(|expiring| &mut expiring.value)
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<ValueType> {
+        self.evict_if_expired(key)?;
+        self.cache.remove(key).map(|expiring| expiring.value)
+    }
+
+    pub fn purge_expired(&mut self) -> usize {
+        let now = self.clock.now();
+        let expired = self
+            .cache
+            .keys()
+            .filter(|key| self.cache.peek(key).unwrap().expires_at <= now)
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+
+        for key in &expired {
+            self.cache.remove(key);
+        }
+
+        expired.len()
+    }
+
+    pub fn size(&self) -> usize {
+        self.cache.size()
+    }
+
+    fn evict_if_expired(&mut self, key: &str) -> Option<()> {
+        if self.cache.peek(key)?.expires_at <= self.clock.now() {
+            self.cache.remove(key);
+            return None;
+        }
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lru_eviction_test() {
+        let mut cache = LruCache::new(3);
+
+        assert_eq!(cache.put("a".to_owned(), 1), None);
+        assert_eq!(cache.put("b".to_owned(), 2), None);
+        assert_eq!(cache.put("c".to_owned(), 3), None);
+        assert_eq!(cache.get("a"), Some(&1));
+
+        assert_eq!(cache.put("d".to_owned(), 4), Some(("b".to_owned(), 2)));
+        assert!(!cache.contains("b"));
+
+        assert_eq!(cache.put("c".to_owned(), 30), None);
+        assert_eq!(cache.put("e".to_owned(), 5), Some(("a".to_owned(), 1)));
+        assert_eq!(cache.peek("d"), Some(&4));
+        assert_eq!(cache.put("f".to_owned(), 6), Some(("d".to_owned(), 4)));
+
+        assert_eq!(cache.remove("c"), Some(30));
+        assert_eq!(cache.size(), 2);
+        *cache.get_mut("e").unwrap() += 10;
+        assert_eq!(cache.pop_lru(), Some(("f".to_owned(), 6)));
+        assert_eq!(cache.pop_lru(), Some(("e".to_owned(), 15)));
+        assert_eq!(cache.pop_lru(), None);
+    }
+
+    #[test]
+    fn lru_many_keys_test() {
+        let mut cache = LruCache::new(100);
+
+        for i in 0..1000 {
+            cache.put(format!("key{}", i), i);
+            if i >= 10 {
+                assert_eq!(cache.get(&format!("key{}", i - 10)), Some(&(i - 10)));
+            }
+        }
+
+        assert_eq!(cache.size(), 100);
+        for i in 0..1000 {
+            let expected = (i >= 900).then_some(i);
+            assert_eq!(cache.peek(&format!("key{}", i)).copied(), expected);
+        }
+    }
+
+    #[test]
+    fn ttl_expiration_test() {
+        let clock = ManualClock::default();
+        let mut cache = TtlCache::with_clock(4, Duration::from_secs(10), clock.clone());
+
+        cache.put("a".to_owned(), 1);
+        cache.put_with_ttl("b".to_owned(), 2, Duration::from_secs(30));
+        cache.put_with_ttl("c".to_owned(), 3, Duration::from_secs(5));
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(cache.get("a"), Some(&1));
+        assert_eq!(cache.get("c"), None);
+        assert_eq!(cache.size(), 2);
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.get("b"), Some(&2));
+
+        cache.put("a".to_owned(), 10);
+        cache.put_with_ttl("d".to_owned(), 4, Duration::from_secs(1));
+        clock.advance(Duration::from_secs(15));
+        assert_eq!(cache.purge_expired(), 2);
+        assert_eq!(cache.size(), 1);
+        assert_eq!(cache.remove("b"), Some(2));
+    }
+}
@@ -0,0 +1,177 @@
+use crate::pearson_map::{Pair, PearsonMap};
+use crate::permutation::Permutation;
+use common::containers::traits::{
+    ErasableContainer, FillableContainer, IterableContainer, SearchableContainer, SizedContainer,
+};
+
+pub struct PearsonMultiMap<ValueType, List>
+where
+    List: Default
+        + FillableContainer<Pair<Vec<ValueType>>>
+        + SearchableContainer<Pair<Vec<ValueType>>>
+        + ErasableContainer<Pair<Vec<ValueType>>>,
+{
+    map: PearsonMap<Vec<ValueType>, List>,
+    size: usize,
+}
+
+impl<ValueType, List> Default for PearsonMultiMap<ValueType, List>
+where
+    List: Default
+        + FillableContainer<Pair<Vec<ValueType>>>
+        + SearchableContainer<Pair<Vec<ValueType>>>
+        + ErasableContainer<Pair<Vec<ValueType>>>,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            map: Default::default(),
+            size: 0usize,
+        }
+    }
+}
+
+impl<ValueType, List> PearsonMultiMap<ValueType, List>
+where
+    List: Default
+        + FillableContainer<Pair<Vec<ValueType>>>
+        + SearchableContainer<Pair<Vec<ValueType>>>
+        + ErasableContainer<Pair<Vec<ValueType>>>,
+{
+    pub fn with_permutation(permutation: Permutation) -> Self {
+        Self {
+            map: PearsonMap::with_permutation(permutation),
+            size: 0usize,
+        }
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            map: PearsonMap::with_seed(seed),
+            size: 0usize,
+        }
+    }
+
+    pub fn randomized() -> Self {
+        Self {
+            map: PearsonMap::randomized(),
+            size: 0usize,
+        }
+    }
+}
+
+impl<ValueType, List> PearsonMultiMap<ValueType, List>
+where
+    List: Default
+        + FillableContainer<Pair<Vec<ValueType>>>
+        + SearchableContainer<Pair<Vec<ValueType>>>
+        + ErasableContainer<Pair<Vec<ValueType>>>
+        + SizedContainer,
+{
+    pub fn put(&mut self, key: String, value: ValueType) {
+        match self.map.find_mut(&key) {
+            Some(values) => values.push(value),
+            None => self.map.put(key, vec![value]),
+        }
+
+        self.size += 1;
+    }
+
+    pub fn get_all(&self, key: &str) -> impl Iterator<Item = &ValueType> {
+        self.map.find(key).into_iter().flatten()
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.map.contains(key)
+    }
+
+    pub fn remove_one(&mut self, key: &str, value: &ValueType) -> Option<ValueType>
+    where
+        ValueType: PartialEq,
+    {
+        let values = self.map.find_mut(key)?;
+        let position = values.iter().position(|found| found == value)?;
+        let removed = values.remove(position);
+
+        if values.is_empty() {
+            self.map.get(key);
+        }
+
+        self.size -= 1;
+        Some(removed)
+    }
+
+    pub fn remove_all(&mut self, key: &str) -> Vec<ValueType> {
+        let removed = self.map.get(key).unwrap_or_default();
+        self.size -= removed.len();
+        removed
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn keys_count(&self) -> usize {
+        self.map.size()
+    }
+}
+
+impl<ValueType, List> PearsonMultiMap<ValueType, List>
+where
+    List: Default
+        + FillableContainer<Pair<Vec<ValueType>>>
+        + SearchableContainer<Pair<Vec<ValueType>>>
+        + ErasableContainer<Pair<Vec<ValueType>>>
+        + IterableContainer<Pair<Vec<ValueType>>>,
+{
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ValueType)> {
+        self.map
+            .iter()
+            .flat_map(|(key, values)| values.iter().map(move |value| (key, value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PearsonArrayMultiMap;
+
+    #[test]
+    fn basic_multimap_test() {
+        let mut multimap = PearsonArrayMultiMap::<i32>::default();
+
+        assert_eq!(multimap.size(), 0);
+        assert_eq!(multimap.get_all("a").count(), 0);
+
+        multimap.put("a".to_owned(), 1);
+        multimap.put("a".to_owned(), 2);
+        multimap.put("a".to_owned(), 1);
+        multimap.put("b".to_owned(), 3);
+
+        assert_eq!(multimap.size(), 4);
+        assert_eq!(multimap.keys_count(), 2);
+        assert_eq!(
+            multimap.get_all("a").copied().collect::<Vec<_>>(),
+            vec![1, 2, 1]
+        );
+        assert_eq!(multimap.get_all("b").copied().collect::<Vec<_>>(), vec![3]);
+        assert_eq!(multimap.iter().count(), 4);
+
+        assert_eq!(multimap.remove_one("a", &1), Some(1));
+        assert_eq!(multimap.remove_one("a", &5), None);
+        assert_eq!(multimap.remove_one("c", &1), None);
+        assert_eq!(
+            multimap.get_all("a").copied().collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+        assert_eq!(multimap.size(), 3);
+
+        assert_eq!(multimap.remove_one("b", &3), Some(3));
+        assert!(!multimap.contains_key("b"));
+        assert_eq!(multimap.keys_count(), 1);
+
+        assert_eq!(multimap.remove_all("a"), vec![2, 1]);
+        assert_eq!(multimap.remove_all("a"), Vec::<i32>::new());
+        assert_eq!(multimap.size(), 0);
+        assert_eq!(multimap.keys_count(), 0);
+    }
+}
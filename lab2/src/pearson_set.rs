@@ -0,0 +1,193 @@
+use crate::pearson_map::{Pair, PearsonMap};
+use crate::permutation::Permutation;
+use common::containers::traits::{
+    ErasableContainer, FillableContainer, IterableContainer, SearchableContainer, SizedContainer,
+};
+
+pub struct PearsonSet<List>
+where
+    List: Default
+        + FillableContainer<Pair<()>>
+        + SearchableContainer<Pair<()>>
+        + ErasableContainer<Pair<()>>,
+{
+    map: PearsonMap<(), List>,
+}
+
+impl<List> Default for PearsonSet<List>
+where
+    List: Default
+        + FillableContainer<Pair<()>>
+        + SearchableContainer<Pair<()>>
+        + ErasableContainer<Pair<()>>,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            map: Default::default(),
+        }
+    }
+}
+
+impl<List> PearsonSet<List>
+where
+    List: Default
+        + FillableContainer<Pair<()>>
+        + SearchableContainer<Pair<()>>
+        + ErasableContainer<Pair<()>>,
+{
+    pub fn with_permutation(permutation: Permutation) -> Self {
+        Self {
+            map: PearsonMap::with_permutation(permutation),
+        }
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            map: PearsonMap::with_seed(seed),
+        }
+    }
+
+    pub fn randomized() -> Self {
+        Self {
+            map: PearsonMap::randomized(),
+        }
+    }
+}
+
+impl<List> PearsonSet<List>
+where
+    List: Default
+        + FillableContainer<Pair<()>>
+        + SearchableContainer<Pair<()>>
+        + ErasableContainer<Pair<()>>
+        + IterableContainer<Pair<()>>
+        + SizedContainer,
+{
+    pub fn insert(&mut self, key: String) -> bool {
+        if self.map.contains(&key) {
+            return false;
+        }
+
+        self.map.put(key, ());
+        true
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.map.contains(key)
+    }
+
+    pub fn remove(&mut self, key: &str) -> bool {
+        self.map.get(key).is_some()
+    }
+
+    pub fn size(&self) -> usize {
+        self.map.size()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.map.keys()
+    }
+
+    pub fn union<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a str> {
+        self.iter().chain(other.difference(self))
+    }
+
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a str> {
+        self.iter().filter(|key| other.contains(key))
+    }
+
+    pub fn difference<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a str> {
+        self.iter().filter(|key| !other.contains(key))
+    }
+
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a str> {
+        self.difference(other).chain(other.difference(self))
+    }
+}
+
+impl<List> FromIterator<String> for PearsonSet<List>
+where
+    List: Default
+        + FillableContainer<Pair<()>>
+        + SearchableContainer<Pair<()>>
+        + ErasableContainer<Pair<()>>
+        + IterableContainer<Pair<()>>
+        + SizedContainer,
+{
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        let mut set = Self::default();
+
+        for key in iter {
+            set.insert(key);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PearsonArraySet;
+
+    fn set_of(keys: &[&str]) -> PearsonArraySet {
+        keys.iter().map(|key| key.to_string()).collect()
+    }
+
+    fn sorted<'a>(keys: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+        let mut keys = keys.collect::<Vec<_>>();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn basic_set_test() {
+        let mut set = PearsonArraySet::default();
+
+        assert_eq!(set.size(), 0);
+        assert!(!set.contains("a"));
+
+        assert!(set.insert("a".to_owned()));
+        assert!(set.insert("b".to_owned()));
+        assert!(!set.insert("a".to_owned()));
+        assert_eq!(set.size(), 2);
+        assert!(set.contains("a"));
+        assert!(set.contains("b"));
+
+        assert!(set.remove("a"));
+        assert!(!set.remove("a"));
+        assert!(!set.contains("a"));
+        assert_eq!(set.size(), 1);
+
+        for i in 0..512 {
+            set.insert(format!("key{}", i));
+        }
+
+        for i in (0..512).step_by(2) {
+            assert!(set.remove(&format!("key{}", i)));
+        }
+
+        for i in 0..512 {
+            assert_eq!(set.contains(&format!("key{}", i)), i % 2 == 1);
+        }
+
+        assert_eq!(set.size(), 257);
+        assert_eq!(set.iter().count(), 257);
+    }
+
+    #[test]
+    fn set_algebra_test() {
+        let lhs = set_of(&["a", "b", "c", "d"]);
+        let rhs = set_of(&["c", "d", "e"]);
+
+        assert_eq!(sorted(lhs.union(&rhs)), vec!["a", "b", "c", "d", "e"]);
+        assert_eq!(sorted(lhs.intersection(&rhs)), vec!["c", "d"]);
+        assert_eq!(sorted(lhs.difference(&rhs)), vec!["a", "b"]);
+        assert_eq!(sorted(rhs.difference(&lhs)), vec!["e"]);
+        assert_eq!(sorted(lhs.symmetric_difference(&rhs)), vec!["a", "b", "e"]);
+
+        let empty = set_of(&[]);
+        assert_eq!(sorted(lhs.union(&empty)), vec!["a", "b", "c", "d"]);
+        assert_eq!(lhs.intersection(&empty).count(), 0);
+    }
+}
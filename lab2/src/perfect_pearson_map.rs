@@ -0,0 +1,427 @@
+use crate::pearson_map::pearson_hash_with;
+use crate::permutation::{generate_permutation, is_permutation, Permutation};
+
+const SMALL_SET_LIMIT: usize = 64;
+const MAX_PERMUTATION_ATTEMPTS: u64 = 1 << 14;
+const MAX_DISPLACEMENT_ATTEMPTS: u64 = 64;
+const KEYS_PER_BUCKET: usize = 4;
+
+const PERMUTATION_TAG: u8 = 0;
+const DISPLACED_TAG: u8 = 1;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum PerfectHashError {
+    DuplicateKey(String),
+    Collision(String, String),
+    SearchExhausted,
+    MalformedTable,
+}
+
+impl std::fmt::Display for PerfectHashError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::DuplicateKey(key) => write!(f, "duplicate key \"{}\"", key),
+            Self::Collision(lhs, rhs) => write!(f, "keys \"{}\" and \"{}\" collide", lhs, rhs),
+            Self::SearchExhausted => write!(f, "no perfect hash function found"),
+            Self::MalformedTable => write!(f, "malformed perfect hash table"),
+        }
+    }
+}
+
+impl std::error::Error for PerfectHashError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PerfectPearsonHash {
+    Permutation(Permutation),
+    Displaced {
+        permutation: Permutation,
+        displacements: Vec<u32>,
+        slots: usize,
+    },
+}
+
+fn wide_hash(permutation: &Permutation, lane: u8, key: &[u8]) -> u32 {
+    (0..4u8).fold(0u32, |hash, i| {
+        (hash << 8) | pearson_hash_with(permutation, lane * 4 + i, key) as u32
+    })
+}
+
+fn displaced_index(
+    permutation: &Permutation,
+    displacement: u32,
+    slots: usize,
+    key: &[u8],
+) -> usize {
+    let (d0, d1) = (
+        displacement as u64 / slots as u64,
+        displacement as u64 % slots as u64,
+    );
+    let f1 = wide_hash(permutation, 1, key) as u64;
+    let f2 = wide_hash(permutation, 2, key) as u64;
+    (f1.wrapping_add(d0.wrapping_mul(f2)).wrapping_add(d1) % slots as u64) as usize
+}
+
+fn bucket_index(permutation: &Permutation, buckets: usize, key: &[u8]) -> usize {
+    wide_hash(permutation, 0, key) as usize % buckets
+}
+
+fn search_permutation(keys: &[&str]) -> Option<Permutation> {
+    (0..MAX_PERMUTATION_ATTEMPTS)
+        .map(generate_permutation)
+        .find(|permutation| {
+            let mut taken = [false; 256];
+            keys.iter().all(|key| {
+                let index = pearson_hash_with(permutation, 0, key.as_bytes()) as usize;
+                !std::mem::replace(&mut taken[index], true)
+            })
+        })
+}
+
+fn search_displacements(permutation: &Permutation, keys: &[&str]) -> Option<Vec<u32>> {
+    let slots = keys.len().max(1);
+    let buckets_count = keys.len().div_ceil(KEYS_PER_BUCKET).max(1);
+
+    let mut buckets = vec![Vec::new(); buckets_count];
+    for key in keys {
+        buckets[bucket_index(permutation, buckets_count, key.as_bytes())].push(key.as_bytes());
+    }
+
+    let mut order = (0..buckets_count).collect::<Vec<_>>();
+    order.sort_by_key(|&bucket| std::cmp::Reverse(buckets[bucket].len()));
+
+    let mut displacements = vec![0u32; buckets_count];
+    let mut taken = vec![false; slots];
+    let mut positions = Vec::new();
+
+    for bucket in order {
+        if buckets[bucket].is_empty() {
+            continue;
+        }
+
+        let found = (0..(slots * slots).min(u32::MAX as usize) as u32).find(|&displacement| {
+            positions.clear();
+            buckets[bucket].iter().all(|key| {
+                let index = displaced_index(permutation, displacement, slots, key);
+                let is_free = !taken[index] && !positions.contains(&index);
+                positions.push(index);
+                is_free
+            })
+        })?;
+
+        for &index in &positions {
+            taken[index] = true;
+        }
+
+        displacements[bucket] = found;
+    }
+
+    Some(displacements)
+}
+
+impl PerfectPearsonHash {
+    pub fn build<Key: AsRef<str>>(keys: &[Key]) -> Result<Self, PerfectHashError> {
+        let mut keys = keys.iter().map(|key| key.as_ref()).collect::<Vec<_>>();
+        keys.sort_unstable();
+
+        if let Some(duplicate) = keys.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(PerfectHashError::DuplicateKey(duplicate[0].to_owned()));
+        }
+
+        if keys.len() <= SMALL_SET_LIMIT {
+            if let Some(permutation) = search_permutation(&keys) {
+                return Ok(Self::Permutation(permutation));
+            }
+        }
+
+        (0..MAX_DISPLACEMENT_ATTEMPTS)
+            .map(generate_permutation)
+            .find_map(|permutation| {
+                search_displacements(&permutation, &keys).map(|displacements| Self::Displaced {
+                    permutation,
+                    displacements,
+                    slots: keys.len().max(1),
+                })
+            })
+            .ok_or(PerfectHashError::SearchExhausted)
+    }
+
+    pub fn index(&self, key: &str) -> usize {
+        match self {
+            Self::Permutation(permutation) => {
+                pearson_hash_with(permutation, 0, key.as_bytes()) as usize
+            }
+            Self::Displaced {
+                permutation,
+                displacements,
+                slots,
+            } => {
+                let bucket = bucket_index(permutation, displacements.len(), key.as_bytes());
+                displaced_index(permutation, displacements[bucket], *slots, key.as_bytes())
+            }
+        }
+    }
+
+    pub fn slots(&self) -> usize {
+        match self {
+            Self::Permutation(permutation) => permutation.len(),
+            Self::Displaced { slots, .. } => *slots,
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        match self {
+            Self::Permutation(permutation) => {
+                bytes.push(PERMUTATION_TAG);
+                bytes.extend_from_slice(permutation);
+            }
+            Self::Displaced {
+                permutation,
+                displacements,
+                slots,
+            } => {
+                bytes.push(DISPLACED_TAG);
+                bytes.extend_from_slice(permutation);
+                bytes.extend_from_slice(&(*slots as u32).to_le_bytes());
+                bytes.extend_from_slice(&(displacements.len() as u32).to_le_bytes());
+                for displacement in displacements {
+                    bytes.extend_from_slice(&displacement.to_le_bytes());
+                }
+            }
+        }
+
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PerfectHashError> {
+        let (&tag, rest) = bytes
+            .split_first()
+            .ok_or(PerfectHashError::MalformedTable)?;
+        let (permutation, rest) = rest
+            .split_first_chunk::<256>()
+            .filter(|(permutation, _)| is_permutation(permutation))
+            .ok_or(PerfectHashError::MalformedTable)?;
+
+        let mut words = rest
+            .chunks(4)
+            .map(|chunk| chunk.try_into().map(u32::from_le_bytes));
+
+        let mut next_word = || {
+            words
+                .next()
+                .and_then(Result::ok)
+                .ok_or(PerfectHashError::MalformedTable)
+        };
+
+        match tag {
+            PERMUTATION_TAG if rest.is_empty() => Ok(Self::Permutation(*permutation)),
+            DISPLACED_TAG => {
+                let slots = next_word()? as usize;
+                let count = next_word()? as usize;
+
+                if slots == 0
+                    || count == 0
+                    || slots > count.saturating_mul(KEYS_PER_BUCKET)
+                    || rest.len() != 8 + 4 * count
+                {
+                    return Err(PerfectHashError::MalformedTable);
+                }
+
+                let displacements = (0..count).map(|_| next_word()).collect::<Result<_, _>>()?;
+
+                Ok(Self::Displaced {
+                    permutation: *permutation,
+                    displacements,
+                    slots,
+                })
+            }
+            _ => Err(PerfectHashError::MalformedTable),
+        }
+    }
+}
+
+pub struct PerfectPearsonMap<ValueType> {
+    hash: PerfectPearsonHash,
+    slots: Vec<Option<(String, ValueType)>>,
+    size: usize,
+}
+
+impl<ValueType> PerfectPearsonMap<ValueType> {
+    pub fn build<I>(entries: I) -> Result<Self, PerfectHashError>
+    where
+        I: IntoIterator<Item = (String, ValueType)>,
+    {
+        let entries = entries.into_iter().collect::<Vec<_>>();
+        let keys = entries
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .collect::<Vec<_>>();
+        let hash = PerfectPearsonHash::build(&keys)?;
+        Self::with_hash(hash, entries)
+    }
+
+    pub fn with_hash<I>(hash: PerfectPearsonHash, entries: I) -> Result<Self, PerfectHashError>
+    where
+        I: IntoIterator<Item = (String, ValueType)>,
+    {
+        let mut slots = Vec::<Option<(String, ValueType)>>::with_capacity(hash.slots());
+        slots.resize_with(hash.slots(), || None);
+        let mut size = 0usize;
+
+        for (key, value) in entries {
+            let index = hash.index(&key);
+
+            if let Some((found_key, _)) = &slots[index] {
+                return Err(if *found_key == key {
+                    PerfectHashError::DuplicateKey(key)
+                } else {
+                    PerfectHashError::Collision(found_key.clone(), key)
+                });
+            }
+
+            slots[index] = Some((key, value));
+            size += 1;
+        }
+
+        Ok(Self { hash, slots, size })
+    }
+
+    pub fn find(&self, key: &str) -> Option<&ValueType> {
+        self.slots[self.hash.index(key)]
+            .as_ref()
+            .filter(|(found_key, _)| found_key == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn find_mut(&mut self, key: &str) -> Option<&mut ValueType> {
+        self.slots[self.hash.index(key)]
+            .as_mut()
+            .filter(|(found_key, _)| found_key == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.find(key).is_some()
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn hash(&self) -> &PerfectPearsonHash {
+        &self.hash
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ValueType)> {
+        self.slots
+            .iter()
+            .flatten()
+            .map(|(key, value)| (key.as_str(), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_perfect(hash: &PerfectPearsonHash, keys: &[String]) {
+        let mut taken = vec![false; hash.slots()];
+        for key in keys {
+            let index = hash.index(key);
+            assert!(index < hash.slots());
+            assert!(
+                !std::mem::replace(&mut taken[index], true),
+                "{} collides",
+                key
+            );
+        }
+    }
+
+    #[test]
+    fn small_set_test() {
+        let keys = [
+            "if", "else", "while", "for", "fn", "let", "mut", "match", "loop", "return",
+        ]
+        .map(String::from);
+
+        let hash = PerfectPearsonHash::build(&keys).unwrap();
+        assert!(matches!(hash, PerfectPearsonHash::Permutation(_)));
+        assert_perfect(&hash, &keys);
+
+        let restored = PerfectPearsonHash::from_bytes(&hash.to_bytes()).unwrap();
+        assert_eq!(restored, hash);
+    }
+
+    #[test]
+    fn large_set_test() {
+        let keys = (0..2000).map(|i| format!("word{}", i)).collect::<Vec<_>>();
+
+        let hash = PerfectPearsonHash::build(&keys).unwrap();
+        assert!(matches!(hash, PerfectPearsonHash::Displaced { .. }));
+        assert_eq!(hash.slots(), keys.len());
+        assert_perfect(&hash, &keys);
+
+        let restored = PerfectPearsonHash::from_bytes(&hash.to_bytes()).unwrap();
+        assert_eq!(restored, hash);
+        assert_perfect(&restored, &keys);
+    }
+
+    #[test]
+    fn errors_test() {
+        assert_eq!(
+            PerfectPearsonHash::build(&["a", "b", "a"]),
+            Err(PerfectHashError::DuplicateKey("a".to_owned()))
+        );
+
+        assert_eq!(
+            PerfectPearsonHash::from_bytes(&[]),
+            Err(PerfectHashError::MalformedTable)
+        );
+
+        let mut bytes = PerfectPearsonHash::build(&["a"]).unwrap().to_bytes();
+        bytes.push(0);
+        assert_eq!(
+            PerfectPearsonHash::from_bytes(&bytes),
+            Err(PerfectHashError::MalformedTable)
+        );
+    }
+
+    #[test]
+    fn oversized_header_test() {
+        let keys = (0..300).map(|i| format!("key{}", i)).collect::<Vec<_>>();
+        let mut bytes = PerfectPearsonHash::build(&keys).unwrap().to_bytes();
+        bytes[257..261].copy_from_slice(&u32::MAX.to_le_bytes());
+
+        assert_eq!(
+            PerfectPearsonHash::from_bytes(&bytes),
+            Err(PerfectHashError::MalformedTable)
+        );
+    }
+
+    #[test]
+    fn perfect_map_test() {
+        let entries = (0..300).map(|i| (format!("key{}", i), i));
+        let mut map = PerfectPearsonMap::build(entries).unwrap();
+
+        assert_eq!(map.size(), 300);
+        for i in 0..300 {
+            assert_eq!(map.find(&format!("key{}", i)), Some(&i));
+        }
+        for i in 300..600 {
+            assert_eq!(map.find(&format!("key{}", i)), None);
+        }
+
+        *map.find_mut("key7").unwrap() = -7;
+        assert_eq!(map.find("key7"), Some(&-7));
+        assert_eq!(map.iter().count(), 300);
+
+        let table = map.hash().to_bytes();
+        let reloaded = PerfectPearsonMap::with_hash(
+            PerfectPearsonHash::from_bytes(&table).unwrap(),
+            (0..300).map(|i| (format!("key{}", i), i * 2)),
+        )
+        .unwrap();
+        assert_eq!(reloaded.find("key150"), Some(&300));
+    }
+}
@@ -0,0 +1,101 @@
+pub type Permutation = [u8; 256];
+
+#[derive(Clone)]
+pub(crate) struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    #[inline(always)]
+    pub(crate) const fn new(seed: u64) -> Self {
+        Self {
+            state: (seed ^ 0x9E37_79B9_7F4A_7C15) | 1,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) const fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    #[inline(always)]
+    pub(crate) const fn next_below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+}
+
+pub const fn generate_permutation(seed: u64) -> Permutation {
+    let mut permutation = [0u8; 256];
+    let mut i = 0;
+    while i < permutation.len() {
+        permutation[i] = i as u8;
+        i += 1;
+    }
+
+    let mut rng = XorShift64::new(seed);
+    let mut i = permutation.len() - 1;
+    while i > 0 {
+        let j = rng.next_below(i as u64 + 1) as usize;
+        let swapped = permutation[i];
+        permutation[i] = permutation[j];
+        permutation[j] = swapped;
+        i -= 1;
+    }
+
+    assert!(is_permutation(&permutation));
+    permutation
+}
+
+pub fn random_seed() -> u64 {
+    use std::hash::BuildHasher;
+    std::collections::hash_map::RandomState::new().hash_one(0x5EED_u64)
+}
+
+pub const fn is_permutation(table: &Permutation) -> bool {
+    let mut seen = [false; 256];
+    let mut i = 0;
+    while i < table.len() {
+        if seen[table[i] as usize] {
+            return false;
+        }
+        seen[table[i] as usize] = true;
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_permutation_test() {
+        for seed in 0..32 {
+            let mut permutation = generate_permutation(seed);
+            assert_eq!(permutation, generate_permutation(seed));
+
+            permutation.sort();
+            assert!(permutation
+                .iter()
+                .enumerate()
+                .all(|(i, &x)| i == x as usize));
+        }
+
+        assert_ne!(generate_permutation(1), generate_permutation(2));
+    }
+
+    #[test]
+    fn validator_test() {
+        let identity: Permutation = std::array::from_fn(|i| i as u8);
+        assert!(is_permutation(&identity));
+        assert!(is_permutation(&generate_permutation(42)));
+
+        let mut duplicated = identity;
+        duplicated[0xAA] = 0x2A;
+        assert!(!is_permutation(&duplicated));
+        assert!(!is_permutation(&[0u8; 256]));
+    }
+}
@@ -0,0 +1,555 @@
+use crate::pearson_map::{Pair, PearsonMap};
+use crate::PersonArrayMap;
+use common::containers::traits::{
+    ErasableContainer, FillableContainer, IterableContainer, SearchableContainer, SizedContainer,
+};
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const SNAPSHOT_MAGIC: &[u8; 4] = b"PSNP";
+const LOG_MAGIC: &[u8; 4] = b"PWAL";
+const FORMAT_VERSION: u16 = 1;
+const HEADER_LEN: usize = 6;
+
+const SNAPSHOT_FILE: &str = "snapshot.bin";
+const SNAPSHOT_TMP_FILE: &str = "snapshot.bin.tmp";
+const LOG_FILE: &str = "wal.log";
+
+const PUT_RECORD: u8 = 1;
+const REMOVE_RECORD: u8 = 2;
+const MIN_RECORDS_BEFORE_COMPACTION: usize = 1024;
+
+pub trait Codec: Sized {
+    fn encode(&self, output: &mut Vec<u8>);
+    fn decode(input: &[u8]) -> Option<Self>;
+}
+
+impl Codec for String {
+    fn encode(&self, output: &mut Vec<u8>) {
+        output.extend_from_slice(self.as_bytes());
+    }
+
+    fn decode(input: &[u8]) -> Option<Self> {
+        String::from_utf8(input.to_vec()).ok()
+    }
+}
+
+macro_rules! impl_integer_codec {
+    ($($integer:ty),*) => {
+        $(
+            impl Codec for $integer {
+                fn encode(&self, output: &mut Vec<u8>) {
+                    output.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn decode(input: &[u8]) -> Option<Self> {
+                    input.try_into().ok().map(<$integer>::from_le_bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_codec!(i32, i64, u32, u64);
+
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_owned())
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        (self.bytes.len() >= len).then(|| {
+            let (taken, rest) = self.bytes.split_at(len);
+            self.bytes = rest;
+            taken
+        })
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.take(8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn chunk(&mut self) -> Option<&'a [u8]> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+}
+
+fn push_chunk(output: &mut Vec<u8>, chunk: &[u8]) {
+    output.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+    output.extend_from_slice(chunk);
+}
+
+fn push_header(output: &mut Vec<u8>, magic: &[u8; 4]) {
+    output.extend_from_slice(magic);
+    output.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+}
+
+fn check_header(reader: &mut Reader, magic: &[u8; 4]) -> io::Result<()> {
+    if reader.take(4) != Some(magic.as_slice()) {
+        return Err(invalid_data("bad magic number"));
+    }
+
+    match reader
+        .take(2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+    {
+        Some(FORMAT_VERSION) => Ok(()),
+        _ => Err(invalid_data("unsupported format version")),
+    }
+}
+
+pub fn save_snapshot<ValueType, List>(
+    map: &PearsonMap<ValueType, List>,
+    path: &Path,
+) -> io::Result<()>
+where
+    ValueType: Default + Codec,
+    List: Default
+        + FillableContainer<Pair<ValueType>>
+        + SearchableContainer<Pair<ValueType>>
+        + ErasableContainer<Pair<ValueType>>
+        + IterableContainer<Pair<ValueType>>,
+{
+    let mut bytes = Vec::new();
+    push_header(&mut bytes, SNAPSHOT_MAGIC);
+
+    let entries = map.iter().collect::<Vec<_>>();
+    bytes.extend_from_slice(&(entries.len() as u64).to_le_bytes());
+
+    let mut value_bytes = Vec::new();
+    for (key, value) in entries {
+        push_chunk(&mut bytes, key.as_bytes());
+        value_bytes.clear();
+        value.encode(&mut value_bytes);
+        push_chunk(&mut bytes, &value_bytes);
+    }
+
+    bytes.extend_from_slice(&crc32(&bytes).to_le_bytes());
+
+    let mut file = File::create(path)?;
+    file.write_all(&bytes)?;
+    file.sync_all()
+}
+
+pub fn load_snapshot<ValueType, List>(path: &Path) -> io::Result<PearsonMap<ValueType, List>>
+where
+    ValueType: Default + Codec,
+    List: Default
+        + FillableContainer<Pair<ValueType>>
+        + SearchableContainer<Pair<ValueType>>
+        + ErasableContainer<Pair<ValueType>>
+        + SizedContainer,
+{
+    let bytes = std::fs::read(path)?;
+    let (content, checksum) = bytes
+        .split_last_chunk::<4>()
+        .ok_or_else(|| invalid_data("snapshot is too short"))?;
+
+    if crc32(content) != u32::from_le_bytes(*checksum) {
+        return Err(invalid_data("snapshot checksum mismatch"));
+    }
+
+    let mut reader = Reader { bytes: content };
+    check_header(&mut reader, SNAPSHOT_MAGIC)?;
+
+    let truncated = || invalid_data("snapshot is truncated");
+    let count = reader.u64().ok_or_else(truncated)?;
+    let mut map = PearsonMap::default();
+
+    for _ in 0..count {
+        let key = reader.chunk().ok_or_else(truncated)?;
+        let value = reader.chunk().ok_or_else(truncated)?;
+        let key = String::decode(key).ok_or_else(|| invalid_data("snapshot key is not UTF-8"))?;
+        let value = ValueType::decode(value).ok_or_else(|| invalid_data("bad snapshot value"))?;
+        map.put(key, value);
+    }
+
+    if !reader.bytes.is_empty() {
+        return Err(invalid_data("trailing bytes after snapshot entries"));
+    }
+
+    Ok(map)
+}
+
+enum LogRecord<ValueType> {
+    Put(String, ValueType),
+    Remove(String),
+}
+
+fn decode_record<ValueType: Codec>(payload: &[u8]) -> Option<LogRecord<ValueType>> {
+    let mut reader = Reader { bytes: payload };
+    let tag = reader.u8()?;
+    let key = String::decode(reader.chunk()?)?;
+
+    let record = match tag {
+        PUT_RECORD => LogRecord::Put(key, ValueType::decode(reader.chunk()?)?),
+        REMOVE_RECORD => LogRecord::Remove(key),
+        _ => return None,
+    };
+
+    reader.bytes.is_empty().then_some(record)
+}
+
+pub struct DurablePearsonMap<ValueType>
+where
+    ValueType: Default + Codec,
+{
+    map: PersonArrayMap<ValueType>,
+    dir: PathBuf,
+    log: File,
+    log_records: usize,
+}
+
+impl<ValueType> DurablePearsonMap<ValueType>
+where
+    ValueType: Default + Codec,
+{
+    pub fn open(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir)?;
+
+        let snapshot_path = dir.join(SNAPSHOT_FILE);
+        let mut map = match load_snapshot(&snapshot_path) {
+            Err(error) if error.kind() == io::ErrorKind::NotFound => PersonArrayMap::default(),
+            result => result?,
+        };
+
+        let log_path = dir.join(LOG_FILE);
+        let log_records = Self::replay(&log_path, &mut map)?;
+        let log = OpenOptions::new().append(true).open(&log_path)?;
+
+        Ok(Self {
+            map,
+            dir,
+            log,
+            log_records,
+        })
+    }
+
+    fn replay(log_path: &Path, map: &mut PersonArrayMap<ValueType>) -> io::Result<usize> {
+        let bytes = match std::fs::read(log_path) {
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Vec::new(),
+            result => result?,
+        };
+
+        if bytes.len() < HEADER_LEN {
+            let mut header = Vec::new();
+            push_header(&mut header, LOG_MAGIC);
+            std::fs::write(log_path, header)?;
+            return Ok(0);
+        }
+
+        let mut reader = Reader { bytes: &bytes };
+        check_header(&mut reader, LOG_MAGIC)?;
+
+        let mut records = 0usize;
+        let mut valid_len = HEADER_LEN;
+
+        while let Some(record) = Self::next_record(&mut reader) {
+            match record {
+                LogRecord::Put(key, value) => map.put(key, value),
+                LogRecord::Remove(key) => {
+                    map.get(&key);
+                }
+            }
+
+            records += 1;
+            valid_len = bytes.len() - reader.bytes.len();
+        }
+
+        if valid_len < bytes.len() {
+            OpenOptions::new()
+                .write(true)
+                .open(log_path)?
+                .set_len(valid_len as u64)?;
+        }
+
+        Ok(records)
+    }
+
+    fn next_record(reader: &mut Reader) -> Option<LogRecord<ValueType>> {
+        let len = reader.u32()? as usize;
+        let checksum = reader.u32()?;
+        let payload = reader.take(len)?;
+
+        (crc32(payload) == checksum)
+            .then(|| decode_record(payload))
+            .flatten()
+    }
+
+    fn append(&mut self, record: LogRecord<&ValueType>) -> io::Result<()> {
+        let mut payload = Vec::new();
+        let (tag, key) = match &record {
+            LogRecord::Put(key, _) => (PUT_RECORD, key),
+            LogRecord::Remove(key) => (REMOVE_RECORD, key),
+        };
+
+        payload.push(tag);
+        push_chunk(&mut payload, key.as_bytes());
+
+        if let LogRecord::Put(_, value) = record {
+            let mut value_bytes = Vec::new();
+            value.encode(&mut value_bytes);
+            push_chunk(&mut payload, &value_bytes);
+        }
+
+        let mut bytes = Vec::with_capacity(payload.len() + 8);
+        bytes.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&crc32(&payload).to_le_bytes());
+        bytes.extend_from_slice(&payload);
+
+        self.log.write_all(&bytes)?;
+        self.log.flush()?;
+        self.log_records += 1;
+        Ok(())
+    }
+
+    fn compact_if_needed(&mut self) -> io::Result<()> {
+        if self.log_records > MIN_RECORDS_BEFORE_COMPACTION.max(2 * self.map.size()) {
+            self.compact()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn put(&mut self, key: String, value: ValueType) -> io::Result<()> {
+        self.append(LogRecord::Put(key.clone(), &value))?;
+        self.map.put(key, value);
+        self.compact_if_needed()
+    }
+
+    pub fn remove(&mut self, key: &str) -> io::Result<Option<ValueType>> {
+        if !self.map.contains(key) {
+            return Ok(None);
+        }
+
+        self.append(LogRecord::Remove(key.to_owned()))?;
+        let removed = self.map.get(key);
+        self.compact_if_needed()?;
+        Ok(removed)
+    }
+
+    pub fn compact(&mut self) -> io::Result<()> {
+        let tmp_path = self.dir.join(SNAPSHOT_TMP_FILE);
+        save_snapshot(&self.map, &tmp_path)?;
+        std::fs::rename(&tmp_path, self.dir.join(SNAPSHOT_FILE))?;
+
+        self.log.set_len(HEADER_LEN as u64)?;
+        self.log.sync_all()?;
+        self.log_records = 0;
+        Ok(())
+    }
+
+    pub fn sync(&mut self) -> io::Result<()> {
+        self.log.sync_data()
+    }
+
+    pub fn map(&self) -> &PersonArrayMap<ValueType> {
+        &self.map
+    }
+
+    pub fn find(&self, key: &str) -> Option<&ValueType> {
+        self.map.find(key)
+    }
+
+    pub fn size(&self) -> usize {
+        self.map.size()
+    }
+
+    pub fn log_records(&self) -> usize {
+        self.log_records
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("lab2_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn crc32_test() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn snapshot_roundtrip_test() {
+        let dir = temp_dir("snapshot");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(SNAPSHOT_FILE);
+
+        let mut map = PersonArrayMap::<i64>::default();
+        for i in 0..300 {
+            map.put(format!("key{}", i), i * 3);
+        }
+        save_snapshot(&map, &path).unwrap();
+
+        let loaded: PersonArrayMap<i64> = load_snapshot(&path).unwrap();
+        assert_eq!(loaded.size(), 300);
+        for i in 0..300 {
+            assert_eq!(loaded.find(&format!("key{}", i)), Some(&(i * 3)));
+        }
+
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes[20] ^= 0xFF;
+        std::fs::write(&path, &bytes).unwrap();
+        let corrupted = load_snapshot::<i64, _>(&path).map(|_: PersonArrayMap<i64>| ());
+        assert_eq!(corrupted.unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn log_replay_and_compaction_test() {
+        let dir = temp_dir("replay");
+
+        {
+            let mut map = DurablePearsonMap::<String>::open(&dir).unwrap();
+            map.put("England".to_owned(), "London".to_owned()).unwrap();
+            map.put("France".to_owned(), "Paris".to_owned()).unwrap();
+            assert_eq!(map.remove("England").unwrap(), Some("London".to_owned()));
+            assert_eq!(map.remove("England").unwrap(), None);
+            assert_eq!(map.log_records(), 3);
+        }
+
+        {
+            let mut map = DurablePearsonMap::<String>::open(&dir).unwrap();
+            assert_eq!(map.size(), 1);
+            assert_eq!(map.find("France"), Some(&"Paris".to_owned()));
+
+            map.compact().unwrap();
+            assert_eq!(map.log_records(), 0);
+            map.put("Germany".to_owned(), "Berlin".to_owned()).unwrap();
+        }
+
+        let map = DurablePearsonMap::<String>::open(&dir).unwrap();
+        assert_eq!(map.size(), 2);
+        assert_eq!(map.log_records(), 1);
+        assert_eq!(map.find("Germany"), Some(&"Berlin".to_owned()));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn automatic_compaction_test() {
+        let dir = temp_dir("auto_compaction");
+        let mut map = DurablePearsonMap::<u64>::open(&dir).unwrap();
+
+        for i in 0..(3 * MIN_RECORDS_BEFORE_COMPACTION as u64) {
+            map.put("counter".to_owned(), i).unwrap();
+        }
+
+        assert!(map.log_records() <= MIN_RECORDS_BEFORE_COMPACTION);
+        drop(map);
+
+        let map = DurablePearsonMap::<u64>::open(&dir).unwrap();
+        assert_eq!(
+            map.find("counter"),
+            Some(&(3 * MIN_RECORDS_BEFORE_COMPACTION as u64 - 1))
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn compaction_keeps_triggering_operation_test() {
+        let dir = temp_dir("compaction_trigger");
+        let mut map = DurablePearsonMap::<u64>::open(&dir).unwrap();
+
+        let mut i = 0;
+        loop {
+            map.put("counter".to_owned(), i).unwrap();
+            if map.log_records() == 0 {
+                break;
+            }
+            i += 1;
+        }
+        drop(map);
+
+        let mut map = DurablePearsonMap::<u64>::open(&dir).unwrap();
+        assert_eq!(map.find("counter"), Some(&i));
+
+        loop {
+            map.put("temporary".to_owned(), i).unwrap();
+            assert_eq!(map.remove("temporary").unwrap(), Some(i));
+            if map.log_records() == 0 {
+                break;
+            }
+            i += 1;
+        }
+        drop(map);
+
+        let map = DurablePearsonMap::<u64>::open(&dir).unwrap();
+        assert_eq!(map.find("temporary"), None);
+        assert_eq!(map.size(), 1);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn torn_log_recovery_test() {
+        let dir = temp_dir("torn");
+
+        {
+            let mut map = DurablePearsonMap::<String>::open(&dir).unwrap();
+            for i in 0..10 {
+                map.put(format!("key{}", i), format!("value{}", i)).unwrap();
+            }
+        }
+
+        let log_path = dir.join(LOG_FILE);
+        let full_len = std::fs::metadata(&log_path).unwrap().len();
+        for cut in [3u64, 9, 12] {
+            let file = OpenOptions::new().write(true).open(&log_path).unwrap();
+            file.set_len(full_len - cut).unwrap();
+
+            let map = DurablePearsonMap::<String>::open(&dir).unwrap();
+            assert_eq!(map.size(), 9);
+            assert_eq!(map.find("key9"), None);
+            assert_eq!(map.find("key8"), Some(&"value8".to_owned()));
+        }
+
+        {
+            let mut map = DurablePearsonMap::<String>::open(&dir).unwrap();
+            map.put("key10".to_owned(), "value10".to_owned()).unwrap();
+        }
+
+        let map = DurablePearsonMap::<String>::open(&dir).unwrap();
+        assert_eq!(map.size(), 10);
+        assert_eq!(map.find("key10"), Some(&"value10".to_owned()));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
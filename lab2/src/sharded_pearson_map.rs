@@ -0,0 +1,328 @@
+use crate::pearson_map::{pearson_hash_with, PERMUTATION};
+use crate::PersonArrayMap;
+use std::ops::Deref;
+use std::sync::{Arc, RwLock, RwLockReadGuard};
+
+const DEFAULT_SHARDS: usize = 16;
+const SHARD_HASH_STATE: u8 = 0xA5;
+
+fn shard_index(key: &str, shards: usize) -> usize {
+    pearson_hash_with(&PERMUTATION, SHARD_HASH_STATE, key.as_bytes()) as usize % shards
+}
+
+pub struct ValueGuard<'a, ValueType>
+where
+    ValueType: Default,
+{
+    guard: RwLockReadGuard<'a, PersonArrayMap<ValueType>>,
+    key: String,
+}
+
+impl<ValueType> Deref for ValueGuard<'_, ValueType>
+where
+    ValueType: Default,
+{
+    type Target = ValueType;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard
+            .find(&self.key)
+            .expect("Value must stay in the locked shard")
+    }
+}
+
+pub struct ShardedPearsonMap<ValueType>
+where
+    ValueType: Default,
+{
+    shards: Box<[RwLock<PersonArrayMap<ValueType>>]>,
+}
+
+impl<ValueType> Default for ShardedPearsonMap<ValueType>
+where
+    ValueType: Default,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::with_shards(DEFAULT_SHARDS)
+    }
+}
+
+impl<ValueType> ShardedPearsonMap<ValueType>
+where
+    ValueType: Default,
+{
+    pub fn with_shards(shards: usize) -> Self {
+        assert!(
+            (1..=PERMUTATION.len()).contains(&shards),
+            "shards count must be in 1..=256"
+        );
+
+        Self {
+            shards: (0..shards).map(|_| Default::default()).collect(),
+        }
+    }
+
+    pub fn shards_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    fn shard(&self, key: &str) -> &RwLock<PersonArrayMap<ValueType>> {
+        &self.shards[shard_index(key, self.shards.len())]
+    }
+
+    pub fn insert(&self, key: String, value: ValueType) -> Option<ValueType> {
+        let mut shard = self.shard(&key).write().unwrap();
+
+        match shard.find_mut(&key) {
+            Some(found) => Some(std::mem::replace(found, value)),
+            None => {
+                shard.put(key, value);
+                None
+            }
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<ValueGuard<'_, ValueType>> {
+        let guard = self.shard(key).read().unwrap();
+        guard.find(key)?;
+
+        Some(ValueGuard {
+            guard,
+            key: key.to_owned(),
+        })
+    }
+
+    pub fn get_cloned(&self, key: &str) -> Option<ValueType>
+    where
+        ValueType: Clone,
+    {
+        self.shard(key).read().unwrap().find(key).cloned()
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.shard(key).read().unwrap().contains(key)
+    }
+
+    pub fn remove(&self, key: &str) -> Option<ValueType> {
+        self.shard(key).write().unwrap().get(key)
+    }
+
+    pub fn compute<F>(&self, key: String, remapping: F) -> bool
+    where
+        F: FnOnce(Option<ValueType>) -> Option<ValueType>,
+    {
+        let mut shard = self.shard(&key).write().unwrap();
+
+        match remapping(shard.get(&key)) {
+            Some(value) => {
+                shard.put(key, value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.read().unwrap().size())
+            .sum()
+    }
+
+    pub fn for_each<F>(&self, mut action: F)
+    where
+        F: FnMut(&str, &ValueType),
+    {
+        for shard in self.shards.iter() {
+            for (key, value) in shard.read().unwrap().iter() {
+                action(key, value);
+            }
+        }
+    }
+
+    pub fn par_for_each<F>(&self, action: F)
+    where
+        ValueType: Send + Sync,
+        F: Fn(&str, &ValueType) + Sync,
+    {
+        std::thread::scope(|scope| {
+            for shard in self.shards.iter() {
+                let action = &action;
+                scope.spawn(move || {
+                    for (key, value) in shard.read().unwrap().iter() {
+                        action(key, value);
+                    }
+                });
+            }
+        });
+    }
+
+    pub fn snapshot(&self) -> Snapshot<ValueType>
+    where
+        ValueType: Clone,
+    {
+        let shards = self
+            .shards
+            .iter()
+            .map(|shard| {
+                let shard = shard.read().unwrap();
+                let mut copy = PersonArrayMap::default();
+                for (key, value) in shard.iter() {
+                    copy.put(key.to_owned(), value.clone());
+                }
+                copy
+            })
+            .collect();
+
+        Snapshot {
+            shards: Arc::new(shards),
+        }
+    }
+}
+
+pub struct Snapshot<ValueType>
+where
+    ValueType: Default,
+{
+    shards: Arc<Box<[PersonArrayMap<ValueType>]>>,
+}
+
+impl<ValueType> Clone for Snapshot<ValueType>
+where
+    ValueType: Default,
+{
+    fn clone(&self) -> Self {
+        Self {
+            shards: self.shards.clone(),
+        }
+    }
+}
+
+impl<ValueType> Snapshot<ValueType>
+where
+    ValueType: Default,
+{
+    pub fn get(&self, key: &str) -> Option<&ValueType> {
+        self.shards[shard_index(key, self.shards.len())].find(key)
+    }
+
+    pub fn size(&self) -> usize {
+        self.shards.iter().map(|shard| shard.size()).sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ValueType)> {
+        self.shards.iter().flat_map(|shard| shard.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const THREADS: usize = 8;
+    const KEYS_PER_THREAD: usize = 500;
+
+    #[test]
+    fn basic_sharded_map_test() {
+        let map = ShardedPearsonMap::default();
+
+        assert_eq!(map.insert("a".to_owned(), 1), None);
+        assert_eq!(map.insert("a".to_owned(), 2), Some(1));
+        assert_eq!(*map.get("a").unwrap(), 2);
+        assert!(map.get("b").is_none());
+        assert_eq!(map.get_cloned("a"), Some(2));
+
+        assert!(map.compute("a".to_owned(), |value| value.map(|x| x * 10)));
+        assert!(!map.compute("b".to_owned(), |value| value));
+        assert_eq!(map.get_cloned("a"), Some(20));
+
+        let snapshot = map.snapshot();
+        assert_eq!(map.remove("a"), Some(20));
+        assert_eq!(map.remove("a"), None);
+        assert_eq!(map.size(), 0);
+        assert_eq!(snapshot.get("a"), Some(&20));
+        assert_eq!(snapshot.size(), 1);
+    }
+
+    #[test]
+    fn concurrent_stress_test() {
+        let map = ShardedPearsonMap::<usize>::default();
+
+        std::thread::scope(|scope| {
+            for thread in 0..THREADS {
+                let map = &map;
+                scope.spawn(move || {
+                    for i in 0..KEYS_PER_THREAD {
+                        let key = format!("t{}k{}", thread, i);
+                        map.insert(key.clone(), i);
+                        assert_eq!(map.get_cloned(&key), Some(i));
+
+                        map.compute("counter".to_owned(), |value| Some(value.unwrap_or(0) + 1));
+
+                        if i % 2 == 0 {
+                            assert_eq!(map.remove(&key), Some(i));
+                        }
+                    }
+                });
+            }
+        });
+
+        assert_eq!(map.get_cloned("counter"), Some(THREADS * KEYS_PER_THREAD));
+        assert_eq!(map.size(), THREADS * KEYS_PER_THREAD / 2 + 1);
+
+        let visited = AtomicUsize::new(0);
+        let sum = AtomicUsize::new(0);
+        map.par_for_each(|key, value| {
+            if key != "counter" {
+                visited.fetch_add(1, Ordering::Relaxed);
+                sum.fetch_add(*value, Ordering::Relaxed);
+            }
+        });
+
+        let odd_sum: usize = (0..KEYS_PER_THREAD).filter(|i| i % 2 == 1).sum();
+        assert_eq!(visited.into_inner(), THREADS * KEYS_PER_THREAD / 2);
+        assert_eq!(sum.into_inner(), THREADS * odd_sum);
+    }
+
+    #[test]
+    fn concurrent_readers_and_writers_test() {
+        let map = ShardedPearsonMap::<usize>::with_shards(4);
+        for i in 0..256 {
+            map.insert(format!("key{}", i), i);
+        }
+
+        let snapshot = map.snapshot();
+
+        std::thread::scope(|scope| {
+            for _ in 0..THREADS / 2 {
+                let (map, snapshot) = (&map, snapshot.clone());
+                scope.spawn(move || {
+                    for _ in 0..50 {
+                        for i in 0..256 {
+                            let key = format!("key{}", i);
+                            assert_eq!(snapshot.get(&key), Some(&i));
+                            if let Some(value) = map.get(&key) {
+                                assert!(*value >= i);
+                            }
+                        }
+                    }
+                });
+            }
+
+            for _ in 0..THREADS / 2 {
+                let map = &map;
+                scope.spawn(move || {
+                    for i in 0..256 {
+                        map.compute(format!("key{}", i), |value| value.map(|x| x + 1));
+                    }
+                });
+            }
+        });
+
+        let mut total = 0;
+        map.for_each(|_, value| total += value);
+        assert_eq!(total, (0..256).sum::<usize>() + 256 * THREADS / 2);
+    }
+}
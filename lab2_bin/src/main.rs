@@ -1,60 +1,147 @@
-use lab2::PersonArrayMap;
+use shell::{Command, Shell};
 use std::io::{self, BufRead};
+use std::path::PathBuf;
 
-fn main() {
-    let mut map = PersonArrayMap::default();
+mod shell;
+
+struct Batch {
+    key_value_pairs: Vec<(String, String)>,
+    query_keys: Vec<String>,
+}
 
-    let stdin = io::stdin();
-    let mut lines = stdin.lock().lines();
+fn read_batch<R: BufRead>(input: R) -> Result<Batch, String> {
+    let mut lines = input.lines();
+    let mut next_line = |what: &str| -> Result<String, String> {
+        lines
+            .next()
+            .ok_or_else(|| format!("unexpected end of input, expected {}", what))?
+            .map_err(|error| format!("failed to read {}: {}", what, error))
+    };
 
-    let first_line = lines
-        .next()
-        .expect("Failed to read line")
-        .expect("Failed to parse line");
+    let first_line = next_line("\"m n\" header")?;
     let mut parts = first_line.split_whitespace();
-    let m: usize = parts
-        .next()
-        .expect("Missing m value")
-        .parse()
-        .expect("Failed to parse m");
-    let n: usize = parts
-        .next()
-        .expect("Missing n value")
-        .parse()
-        .expect("Failed to parse n");
+    let mut parse_count = |name: &str| -> Result<usize, String> {
+        parts
+            .next()
+            .ok_or_else(|| format!("missing {} value", name))?
+            .parse()
+            .map_err(|error| format!("failed to parse {}: {}", name, error))
+    };
+
+    let m = parse_count("m")?;
+    let n = parse_count("n")?;
 
     println!("m = {}, n = {}", m, n);
 
     let mut key_value_pairs = Vec::with_capacity(m);
     let mut query_keys = Vec::with_capacity(n);
 
-    for _ in 0..m {
-        if let Some(Ok(line)) = lines.next() {
-            let mut kv = line.split_whitespace();
-            let key = kv.next().expect("Missing key").to_string();
-            let value = kv.next().expect("Missing value").to_string();
-            key_value_pairs.push((key, value));
-        }
+    for i in 0..m {
+        let line = next_line("key-value pair")?;
+        let mut kv = line.split_whitespace();
+        let key = kv
+            .next()
+            .ok_or_else(|| format!("pair {}: missing key", i + 1))?;
+        let value = kv
+            .next()
+            .ok_or_else(|| format!("pair {}: missing value", i + 1))?;
+        key_value_pairs.push((key.to_string(), value.to_string()));
     }
 
     for _ in 0..n {
-        if let Some(Ok(key)) = lines.next() {
-            query_keys.push(key);
-        }
+        query_keys.push(next_line("query key")?);
     }
 
-    for (key, value) in key_value_pairs {
-        map.put(key, value);
+    Ok(Batch {
+        key_value_pairs,
+        query_keys,
+    })
+}
+
+fn run_batch(mut shell: Shell) -> Result<(), String> {
+    let batch = read_batch(io::stdin().lock())?;
+    let mut stdout = io::stdout();
+
+    for (key, value) in batch.key_value_pairs {
+        shell
+            .execute(Command::Put(key, value), &mut io::sink())
+            .map_err(|error| error.to_string())?;
     }
 
     println!("-------------------------");
 
-    for key in query_keys {
-        match map.get(&key) {
-            Some(value) => println!("{}", value),
-            None => println!("Key not found"),
+    for key in batch.query_keys {
+        shell
+            .execute(Command::Get(key), &mut stdout)
+            .map_err(|error| error.to_string())?;
+    }
+
+    Ok(())
+}
+
+enum Mode {
+    Batch,
+    Shell,
+    Script,
+}
+
+struct Options {
+    mode: Mode,
+    data_dir: Option<PathBuf>,
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut options = Options {
+        mode: Mode::Batch,
+        data_dir: None,
+    };
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--shell" => options.mode = Mode::Shell,
+            "--script" => options.mode = Mode::Script,
+            "--data-dir" => {
+                let dir = args.next().ok_or("--data-dir expects a directory")?;
+                options.data_dir = Some(PathBuf::from(dir));
+            }
+            other => {
+                return Err(format!(
+                    "unknown option \"{}\", expected --shell, --script or --data-dir <dir>",
+                    other
+                ))
+            }
         }
     }
+
+    Ok(options)
+}
+
+fn run() -> Result<(), String> {
+    let options = parse_options()?;
+
+    let mut shell = match &options.data_dir {
+        Some(dir) => Shell::durable(dir)
+            .map_err(|error| format!("cannot open {}: {}", dir.display(), error))?,
+        None => Shell::default(),
+    };
+
+    let prompt = match options.mode {
+        Mode::Batch => return run_batch(shell),
+        Mode::Shell => Some("> "),
+        Mode::Script => None,
+    };
+
+    shell
+        .run(io::stdin().lock(), &mut io::stdout(), prompt)
+        .map_err(|error| error.to_string())
+}
+
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("error: {}", error);
+        std::process::exit(1);
+    }
 }
 
 /*
@@ -0,0 +1,402 @@
+use lab2::persistence::DurablePearsonMap;
+use lab2::PersonArrayMap;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Put(String, String),
+    Get(String),
+    Del(String),
+    Has(String),
+    Len,
+    Dump,
+    Stats,
+    Load(String),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ShellError {
+    UnterminatedQuote,
+    UnknownCommand(String),
+    Usage(&'static str),
+    Load(String, String),
+    Storage(String),
+}
+
+impl std::fmt::Display for ShellError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::UnterminatedQuote => write!(f, "unterminated quote"),
+            Self::UnknownCommand(name) => write!(f, "unknown command \"{}\"", name),
+            Self::Usage(usage) => write!(f, "usage: {}", usage),
+            Self::Load(path, reason) => write!(f, "cannot load \"{}\": {}", path, reason),
+            Self::Storage(reason) => write!(f, "storage failure: {}", reason),
+        }
+    }
+}
+
+pub fn tokenize(line: &str) -> Result<Vec<String>, ShellError> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let mut token = String::new();
+
+        if c == '"' {
+            chars.next();
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => token.push(chars.next().ok_or(ShellError::UnterminatedQuote)?),
+                    Some(c) => token.push(c),
+                    None => return Err(ShellError::UnterminatedQuote),
+                }
+            }
+        } else {
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                token.push(c);
+                chars.next();
+            }
+        }
+
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+fn quote(token: &str) -> String {
+    if !token.is_empty() && !token.contains(|c: char| c.is_whitespace() || c == '"' || c == '\\') {
+        return token.to_owned();
+    }
+
+    let escaped = token.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{}\"", escaped)
+}
+
+pub fn parse_command(line: &str) -> Result<Option<Command>, ShellError> {
+    let tokens = tokenize(line)?;
+    let Some((name, args)) = tokens.split_first() else {
+        return Ok(None);
+    };
+
+    if name.starts_with('#') {
+        return Ok(None);
+    }
+
+    let command = match (name.to_uppercase().as_str(), args) {
+        ("PUT", [key, value]) => Command::Put(key.clone(), value.clone()),
+        ("PUT", _) => return Err(ShellError::Usage("PUT <key> <value>")),
+        ("GET", [key]) => Command::Get(key.clone()),
+        ("GET", _) => return Err(ShellError::Usage("GET <key>")),
+        ("DEL", [key]) => Command::Del(key.clone()),
+        ("DEL", _) => return Err(ShellError::Usage("DEL <key>")),
+        ("HAS", [key]) => Command::Has(key.clone()),
+        ("HAS", _) => return Err(ShellError::Usage("HAS <key>")),
+        ("LEN", []) => Command::Len,
+        ("LEN", _) => return Err(ShellError::Usage("LEN")),
+        ("DUMP", []) => Command::Dump,
+        ("DUMP", _) => return Err(ShellError::Usage("DUMP")),
+        ("STATS", []) => Command::Stats,
+        ("STATS", _) => return Err(ShellError::Usage("STATS")),
+        ("LOAD", [path]) => Command::Load(path.clone()),
+        ("LOAD", _) => return Err(ShellError::Usage("LOAD <file>")),
+        _ => return Err(ShellError::UnknownCommand(name.clone())),
+    };
+
+    Ok(Some(command))
+}
+
+enum Store {
+    Memory(PersonArrayMap<String>),
+    Durable(DurablePearsonMap<String>),
+}
+
+impl Store {
+    fn map(&self) -> &PersonArrayMap<String> {
+        match self {
+            Self::Memory(map) => map,
+            Self::Durable(map) => map.map(),
+        }
+    }
+
+    fn put(&mut self, key: String, value: String) -> Result<(), ShellError> {
+        match self {
+            Self::Memory(map) => {
+                map.put(key, value);
+                Ok(())
+            }
+            Self::Durable(map) => map
+                .put(key, value)
+                .map_err(|error| ShellError::Storage(error.to_string())),
+        }
+    }
+
+    fn remove(&mut self, key: &str) -> Result<Option<String>, ShellError> {
+        match self {
+            Self::Memory(map) => Ok(map.get(key)),
+            Self::Durable(map) => map
+                .remove(key)
+                .map_err(|error| ShellError::Storage(error.to_string())),
+        }
+    }
+}
+
+pub struct Shell {
+    store: Store,
+}
+
+impl Default for Shell {
+    fn default() -> Self {
+        Self {
+            store: Store::Memory(PersonArrayMap::default()),
+        }
+    }
+}
+
+impl Shell {
+    pub fn durable(data_dir: &Path) -> io::Result<Self> {
+        Ok(Self {
+            store: Store::Durable(DurablePearsonMap::open(data_dir)?),
+        })
+    }
+
+    pub fn execute<W: Write>(&mut self, command: Command, output: &mut W) -> io::Result<()> {
+        match self.try_execute(command, output)? {
+            Ok(()) => Ok(()),
+            Err(error) => writeln!(output, "error: {}", error),
+        }
+    }
+
+    fn try_execute<W: Write>(
+        &mut self,
+        command: Command,
+        output: &mut W,
+    ) -> io::Result<Result<(), ShellError>> {
+        let map = self.store.map();
+
+        match command {
+            Command::Put(key, value) => {
+                if let Err(error) = self.store.put(key, value) {
+                    return Ok(Err(error));
+                }
+                writeln!(output, "OK")?;
+            }
+            Command::Get(key) => match map.find(&key) {
+                Some(value) => writeln!(output, "{}", value)?,
+                None => writeln!(output, "Key not found")?,
+            },
+            Command::Del(key) => match self.store.remove(&key) {
+                Ok(Some(_)) => writeln!(output, "OK")?,
+                Ok(None) => writeln!(output, "Key not found")?,
+                Err(error) => return Ok(Err(error)),
+            },
+            Command::Has(key) => writeln!(output, "{}", map.contains(&key))?,
+            Command::Len => writeln!(output, "{}", map.size())?,
+            Command::Dump => {
+                let mut pairs = map.iter().collect::<Vec<_>>();
+                pairs.sort();
+                for (key, value) in pairs {
+                    writeln!(output, "{} {}", quote(key), quote(value))?;
+                }
+            }
+            Command::Stats => {
+                let bucket_sizes = map.bucket_sizes().collect::<Vec<_>>();
+                let used = bucket_sizes.iter().filter(|&&size| size > 0).count();
+                let longest = bucket_sizes.iter().max().copied().unwrap_or(0);
+                writeln!(output, "size: {}", map.size())?;
+                writeln!(output, "buckets used: {}/{}", used, bucket_sizes.len())?;
+                writeln!(output, "longest chain: {}", longest)?;
+            }
+            Command::Load(path) => match self.load(&path) {
+                Ok(count) => writeln!(output, "loaded {} pairs", count)?,
+                Err(error) => return Ok(Err(error)),
+            },
+        }
+
+        Ok(Ok(()))
+    }
+
+    fn load(&mut self, path: &str) -> Result<usize, ShellError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| ShellError::Load(path.to_owned(), error.to_string()))?;
+
+        let mut pairs = Vec::new();
+        for (line_ndx, line) in content.lines().enumerate() {
+            match tokenize(line)?.as_slice() {
+                [] => {}
+                [key, value] => pairs.push((key.clone(), value.clone())),
+                _ => {
+                    return Err(ShellError::Load(
+                        path.to_owned(),
+                        format!("line {}: expected <key> <value>", line_ndx + 1),
+                    ))
+                }
+            }
+        }
+
+        let count = pairs.len();
+        for (key, value) in pairs {
+            self.store.put(key, value)?;
+        }
+
+        Ok(count)
+    }
+
+    pub fn run<R: BufRead, W: Write>(
+        &mut self,
+        input: R,
+        output: &mut W,
+        prompt: Option<&str>,
+    ) -> io::Result<()> {
+        let mut lines = input.lines();
+
+        loop {
+            if let Some(prompt) = prompt {
+                write!(output, "{}", prompt)?;
+                output.flush()?;
+            }
+
+            let Some(line) = lines.next() else {
+                return Ok(());
+            };
+
+            match parse_command(&line?) {
+                Ok(Some(command)) => self.execute(command, output)?,
+                Ok(None) => {}
+                Err(error) => writeln!(output, "error: {}", error)?,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_script(script: &str) -> String {
+        let mut output = Vec::new();
+        Shell::default()
+            .run(script.as_bytes(), &mut output, None)
+            .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn tokenize_test() {
+        assert_eq!(tokenize("  PUT a  b ").unwrap(), vec!["PUT", "a", "b"]);
+        assert_eq!(
+            tokenize(r#"PUT "New York" "Big \"Apple\"""#).unwrap(),
+            vec!["PUT", "New York", "Big \"Apple\""]
+        );
+        assert_eq!(tokenize(r#"GET """#).unwrap(), vec!["GET", ""]);
+        assert_eq!(tokenize(r#"GET "abc"#), Err(ShellError::UnterminatedQuote));
+    }
+
+    #[test]
+    fn parse_command_test() {
+        assert_eq!(parse_command("").unwrap(), None);
+        assert_eq!(parse_command("# comment").unwrap(), None);
+        assert_eq!(
+            parse_command("put k v").unwrap(),
+            Some(Command::Put("k".to_owned(), "v".to_owned()))
+        );
+        assert_eq!(
+            parse_command("PUT k"),
+            Err(ShellError::Usage("PUT <key> <value>"))
+        );
+        assert_eq!(
+            parse_command("FROB"),
+            Err(ShellError::UnknownCommand("FROB".to_owned()))
+        );
+    }
+
+    #[test]
+    fn script_test() {
+        let script = r#"
+# countries and capitals
+PUT England London
+PUT France Paris
+PUT "United Kingdom" "London, UK"
+GET France
+GET Spain
+HAS England
+DEL England
+DEL England
+HAS England
+LEN
+PUT France "Paris, FR"
+DUMP
+STATS
+GET
+LOAD /nonexistent/file.txt
+"#;
+
+        let expected = r#"OK
+OK
+OK
+Paris
+Key not found
+true
+OK
+Key not found
+false
+2
+OK
+France "Paris, FR"
+"United Kingdom" "London, UK"
+size: 2
+buckets used: 2/256
+longest chain: 1
+error: usage: GET <key>
+error: cannot load "/nonexistent/file.txt": No such file or directory (os error 2)
+"#;
+
+        assert_eq!(run_script(script), expected);
+    }
+
+    #[test]
+    fn load_test() {
+        let path = std::env::temp_dir().join(format!("lab2_shell_load_{}.txt", std::process::id()));
+        std::fs::write(&path, "a 1\n\n\"b c\" 2\n").unwrap();
+
+        let script = format!("LOAD \"{}\"\nGET \"b c\"\nLEN\n", path.display());
+        assert_eq!(run_script(&script), "loaded 2 pairs\n2\n2\n");
+
+        std::fs::write(&path, "a 1 extra\n").unwrap();
+        let output = run_script(&format!("LOAD \"{}\"\nLEN\n", path.display()));
+        assert!(output.ends_with("line 1: expected <key> <value>\n0\n"));
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn durable_shell_test() {
+        let dir = std::env::temp_dir().join(format!("lab2_shell_data_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let run_durable = |script: &str| {
+            let mut output = Vec::new();
+            Shell::durable(&dir)
+                .unwrap()
+                .run(script.as_bytes(), &mut output, None)
+                .unwrap();
+            String::from_utf8(output).unwrap()
+        };
+
+        assert_eq!(run_durable("PUT a 1\nPUT b 2\nDEL a\n"), "OK\nOK\nOK\n");
+        assert_eq!(run_durable("DUMP\nPUT c 3\n"), "b 2\nOK\n");
+        assert_eq!(run_durable("LEN\nHAS a\n"), "2\nfalse\n");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,21 +1,36 @@
-use std::cell::{Ref, RefCell, RefMut};
 use crate::array_bst::InsertionPlace::NodeAlreadyExists;
-use crate::traits::{NodeIdentifiableTree, ParentifiedTree, RotatableTree};
-use crate::Tree;
+use crate::export::{self, LoadError};
+use crate::guards::PlainGuards;
+use crate::ordered_tree::{self, RangeIter};
+use crate::observer::{Event, NoObserver, TreeObserver};
+use crate::traits::{
+    BulkLoadableTree, NodeIdentifiableTree, ObservableTree, OrderedTree, ParentifiedTree,
+    RotatableTree, TraversableTree, Tree,
+};
+use crate::traversal::{self, InOrder, Traverse};
+use std::ops::RangeBounds;
+use std::str::FromStr;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct NodeId {
     node_ndx: usize,
+    generation: u32,
 }
 
 #[derive(Default)]
 struct Node<Key, Value> {
     key: Key,
-    val: RefCell<Value>,
+    val: Value,
     id: NodeId,
     parent_id: Option<NodeId>,
     left_son_id: Option<NodeId>,
     right_son_id: Option<NodeId>,
+    size: usize,
+}
+
+struct Slot<Key, Value> {
+    generation: u32,
+    node: Option<Node<Key, Value>>,
 }
 
 enum SonType {
@@ -29,25 +44,31 @@ enum InsertionPlace {
     NodeAlreadyExists(NodeId),
 }
 
-pub struct ArrayBST<Key, Value>
+pub struct ArrayBST<Key, Value, Observer = NoObserver>
 where
     Key: Ord,
     Value: Clone,
+    Observer: TreeObserver<Key>,
 {
-    array: Vec<Node<Key, Value>>,
+    array: Vec<Slot<Key, Value>>,
+    free_slots: Vec<usize>,
     root_id: Option<NodeId>,
+    observer: Observer,
 }
 
-impl<Key, Value> Default for ArrayBST<Key, Value>
+impl<Key, Value, Observer> Default for ArrayBST<Key, Value, Observer>
 where
     Key: Ord,
     Value: Clone,
+    Observer: TreeObserver<Key> + Default,
 {
     #[inline(always)]
     fn default() -> Self {
         Self {
             array: Default::default(),
+            free_slots: Default::default(),
             root_id: None,
+            observer: Default::default(),
         }
     }
 }
@@ -56,6 +77,17 @@ impl<Key, Value> ArrayBST<Key, Value>
 where
     Key: Ord,
     Value: Clone,
+{
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl<Key, Value, Observer> ArrayBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
 {
     fn find_insertion_place(&self, key: &Key) -> InsertionPlace {
         let mut maybe_current_node = self.get_root_node();
@@ -82,22 +114,196 @@ where
     }
 
     fn get_node_by_id(&self, node_id: NodeId) -> Option<&Node<Key, Value>> {
-        self.array.get(node_id.node_ndx)
+        self.array
+            .get(node_id.node_ndx)
+            .filter(|slot| slot.generation == node_id.generation)
+            .and_then(|slot| slot.node.as_ref())
     }
 
     fn get_node_by_id_mut(&mut self, node_id: NodeId) -> Option<&mut Node<Key, Value>> {
-        self.array.get_mut(node_id.node_ndx)
+        self.array
+            .get_mut(node_id.node_ndx)
+            .filter(|slot| slot.generation == node_id.generation)
+            .and_then(|slot| slot.node.as_mut())
+    }
+
+    fn allocate_node(&mut self, key: Key, val: Value, parent_id: Option<NodeId>) -> NodeId {
+        let node_ndx = self.free_slots.pop().unwrap_or_else(|| {
+            self.array.push(Slot {
+                generation: 0,
+                node: None,
+            });
+            self.array.len() - 1
+        });
+
+        let slot = &mut self.array[node_ndx];
+        let node_id = NodeId {
+            node_ndx,
+            generation: slot.generation,
+        };
+
+        slot.node = Some(Node {
+            key,
+            val,
+            id: node_id,
+            parent_id,
+            left_son_id: None,
+            right_son_id: None,
+            size: 1,
+        });
+
+        node_id
+    }
+
+    fn free_node(&mut self, node_id: NodeId) -> Option<Node<Key, Value>> {
+        let slot = self
+            .array
+            .get_mut(node_id.node_ndx)
+            .filter(|slot| slot.generation == node_id.generation)?;
+        let node = slot.node.take()?;
+
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free_slots.push(node_id.node_ndx);
+
+        Some(node)
+    }
+
+    fn build_balanced<I>(&mut self, entries: &mut I, len: usize) -> Option<NodeId>
+    where
+        I: Iterator<Item = (Key, Value)>,
+    {
+        if len == 0 {
+            return None;
+        }
+
+        let left_son_id = self.build_balanced(entries, len / 2);
+        let (key, val) = entries.next().expect("Entry not found");
+        let node_id = self.allocate_node(key, val, None);
+        let right_son_id = self.build_balanced(entries, len - len / 2 - 1);
+
+        for son_id in [left_son_id, right_son_id].into_iter().flatten() {
+            self.get_node_by_id_mut(son_id).expect("Son not found").parent_id = Some(node_id);
+        }
+
+        let node = self.get_node_by_id_mut(node_id).expect("Node not found");
+        node.left_son_id = left_son_id;
+        node.right_son_id = right_son_id;
+        node.size = len;
+
+        Some(node_id)
+    }
+
+    fn get_size(&self, node_id: Option<NodeId>) -> usize {
+        node_id
+            .and_then(|node_id| self.get_node_by_id(node_id))
+            .map_or(0, |node| node.size)
+    }
+
+    fn update_size(&mut self, node_id: NodeId) {
+        let Some(node) = self.get_node_by_id(node_id) else {
+            return;
+        };
+
+        let size = 1 + self.get_size(node.left_son_id) + self.get_size(node.right_son_id);
+        if let Some(node) = self.get_node_by_id_mut(node_id) {
+            node.size = size;
+        }
+    }
+
+    fn update_sizes_upwards(&mut self, mut maybe_node_id: Option<NodeId>) {
+        while let Some(node_id) = maybe_node_id {
+            self.update_size(node_id);
+            maybe_node_id = self.get_parent_id(node_id);
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.array.len() - self.free_slots.len()
+    }
+
+    pub fn compact(&mut self) {
+        let generation = self
+            .array
+            .iter()
+            .map(|slot| slot.generation)
+            .max()
+            .map_or(0, |generation| generation.wrapping_add(1));
+
+        let mut order = Vec::with_capacity(self.size());
+        order.extend(self.root_id);
+
+        let mut ndx = 0;
+        while let Some(&node_id) = order.get(ndx) {
+            let node = self.get_node_by_id(node_id).expect("Node not found");
+            order.extend(node.left_son_id);
+            order.extend(node.right_son_id);
+            ndx += 1;
+        }
+
+        let mut new_ndx = vec![0; self.array.len()];
+        for (ndx, node_id) in order.iter().enumerate() {
+            new_ndx[node_id.node_ndx] = ndx;
+        }
+
+        let renumber = |node_id: NodeId| NodeId {
+            node_ndx: new_ndx[node_id.node_ndx],
+            generation,
+        };
+
+        let mut array = Vec::with_capacity(order.len());
+        for node_id in order {
+            let mut node = self.array[node_id.node_ndx].node.take().expect("Node not found");
+            node.id = renumber(node.id);
+            node.parent_id = node.parent_id.map(renumber);
+            node.left_son_id = node.left_son_id.map(renumber);
+            node.right_son_id = node.right_son_id.map(renumber);
+
+            array.push(Slot {
+                generation,
+                node: Some(node),
+            });
+        }
+
+        self.root_id = self.root_id.map(renumber);
+        self.array = array;
+        self.free_slots.clear();
     }
 
     fn get_root_node(&self) -> Option<&Node<Key, Value>> {
         self.get_node_by_id(self.root_id?)
     }
+
+    fn get_minimum_id(&self, mut node_id: NodeId) -> NodeId {
+        while let Some(left_son_id) = self.get_left_son_id(node_id) {
+            node_id = left_son_id;
+        }
+
+        node_id
+    }
+
+    fn transplant(&mut self, node_id: NodeId, replacement_id: Option<NodeId>) {
+        let is_left_son = self.is_left_son(node_id);
+        let parent_id = self.get_parent_id(node_id);
+
+        match parent_id.and_then(|parent_id| self.get_node_by_id_mut(parent_id)) {
+            Some(parent) if is_left_son => parent.left_son_id = replacement_id,
+            Some(parent) => parent.right_son_id = replacement_id,
+            None => self.root_id = replacement_id,
+        }
+
+        if let Some(replacement) =
+            replacement_id.and_then(|replacement_id| self.get_node_by_id_mut(replacement_id))
+        {
+            replacement.parent_id = parent_id;
+        }
+    }
 }
 
-impl<Key, Value> Tree<Key, Value> for ArrayBST<Key, Value>
+impl<Key, Value, Observer> Tree<Key, Value> for ArrayBST<Key, Value, Observer>
 where
     Key: Ord,
     Value: Clone,
+    Observer: TreeObserver<Key>,
 {
     fn insert(&mut self, key: Key, val: Value) {
         let _ = self.insert_and_get_id(key, val);
@@ -106,52 +312,36 @@ where
     fn get(&self, key: &Key) -> Option<Value> {
         match self.find_insertion_place(key) {
             InsertionPlace::NodeAlreadyExists(node_id) => {
-                self.get_node_by_id(node_id).map(|node| node.val.borrow().clone())
+                self.get_node_by_id(node_id).map(|node| node.val.clone())
             }
             _ => None,
         }
     }
 
-    fn delete(&mut self, _key: &Key) -> Option<Value> {
-        todo!()
+    fn delete(&mut self, key: &Key) -> Option<Value> {
+        let node_id = self.get_id(key)?;
+        self.delete_by_id(node_id)
     }
 }
 
-impl<Key, Value> NodeIdentifiableTree<Key, Value, NodeId> for ArrayBST<Key, Value>
+impl<Key, Value, Observer> NodeIdentifiableTree<Key, Value, NodeId>
+    for ArrayBST<Key, Value, Observer>
 where
     Key: Ord,
     Value: Clone,
+    Observer: TreeObserver<Key>,
 {
+    type Guards = PlainGuards;
+
     fn insert_and_get_id(&mut self, key: Key, val: Value) -> NodeId {
         let inserted_node_id = match self.find_insertion_place(&key) {
             InsertionPlace::Root => {
-                let inserted_node_id = NodeId { node_ndx: 0 };
+                let inserted_node_id = self.allocate_node(key, val, None);
                 self.root_id = Some(inserted_node_id);
-
-                self.array.push(Node {
-                    key,
-                    val: RefCell::new(val),
-                    id: inserted_node_id,
-                    parent_id: None,
-                    left_son_id: None,
-                    right_son_id: None,
-                });
-
                 inserted_node_id
             }
             InsertionPlace::SonOf(parent_id, son_type) => {
-                let inserted_node_id = NodeId {
-                    node_ndx: self.array.len(),
-                };
-
-                self.array.push(Node {
-                    key,
-                    val: RefCell::new(val),
-                    id: inserted_node_id,
-                    parent_id: Some(parent_id),
-                    left_son_id: None,
-                    right_son_id: None,
-                });
+                let inserted_node_id = self.allocate_node(key, val, Some(parent_id));
 
                 let parent = self
                     .get_node_by_id_mut(parent_id)
@@ -166,26 +356,97 @@ where
                     }
                 }
 
+                self.update_sizes_upwards(Some(parent_id));
                 inserted_node_id
             }
             InsertionPlace::NodeAlreadyExists(node_id) => {
                 let node = self.get_node_by_id_mut(node_id).expect("Node not found");
-                *node.val.borrow_mut() = val;
-                node.id
+                node.val = val;
+                self.notify(node_id, Event::Replace);
+                return node_id;
             }
         };
 
-        println!("inserted_node_id = {:?}", inserted_node_id);
-
+        self.notify(inserted_node_id, Event::Insert);
         inserted_node_id
     }
 
-    fn get_by_id(&self, node_id: NodeId) -> Option<Ref<Value>> {
-        self.get_node_by_id(node_id).map(|node| node.val.borrow())
+    fn get_id(&self, key: &Key) -> Option<NodeId> {
+        match self.find_insertion_place(key) {
+            InsertionPlace::NodeAlreadyExists(node_id) => Some(node_id),
+            _ => None,
+        }
+    }
+
+    fn delete_by_id(&mut self, node_id: NodeId) -> Option<Value> {
+        self.get_node_by_id(node_id)?;
+        self.notify(node_id, Event::Delete);
+
+        let node = self.get_node_by_id(node_id)?;
+        let (left_son_id, right_son_id) = (node.left_son_id, node.right_son_id);
+        let parent_id = node.parent_id;
+
+        let resized_id = match (left_son_id, right_son_id) {
+            (None, _) => {
+                self.transplant(node_id, right_son_id);
+                parent_id
+            }
+            (_, None) => {
+                self.transplant(node_id, left_son_id);
+                parent_id
+            }
+            (Some(left_son_id), Some(right_son_id)) => {
+                let successor_id = self.get_minimum_id(right_son_id);
+                let resized_id = if successor_id == right_son_id {
+                    Some(successor_id)
+                } else {
+                    self.get_parent_id(successor_id)
+                };
+
+                if successor_id != right_son_id {
+                    let successor_right_son_id = self.get_right_son_id(successor_id);
+                    self.transplant(successor_id, successor_right_son_id);
+
+                    let successor = self.get_node_by_id_mut(successor_id)?;
+                    successor.right_son_id = Some(right_son_id);
+                    self.get_node_by_id_mut(right_son_id)?.parent_id = Some(successor_id);
+                }
+
+                self.transplant(node_id, Some(successor_id));
+
+                let successor = self.get_node_by_id_mut(successor_id)?;
+                successor.left_son_id = Some(left_son_id);
+                self.get_node_by_id_mut(left_son_id)?.parent_id = Some(successor_id);
+
+                resized_id
+            }
+        };
+
+        let node = self.free_node(node_id)?;
+        self.update_sizes_upwards(resized_id);
+
+        Some(node.val)
+    }
+
+    fn get_key_by_id(&self, node_id: NodeId) -> Option<&Key> {
+        self.get_node_by_id(node_id).map(|node| &node.key)
+    }
+
+    fn get_subtree_size(&self, node_id: NodeId) -> usize {
+        self.get_size(Some(node_id))
+    }
+
+    fn get_by_id(&self, node_id: NodeId) -> Option<&Value> {
+        self.get_node_by_id(node_id).map(|node| &node.val)
+    }
+
+    fn get_by_id_mut(&mut self, node_id: NodeId) -> Option<&mut Value> {
+        self.get_node_by_id_mut(node_id).map(|node| &mut node.val)
     }
 
-    fn get_by_id_mut(&mut self, node_id: NodeId) -> Option<RefMut<Value>> {
-        self.get_node_by_id_mut(node_id).map(|node| node.val.borrow_mut())
+    fn get_entry_by_id_mut(&mut self, node_id: NodeId) -> Option<(&Key, &mut Value)> {
+        self.get_node_by_id_mut(node_id)
+            .map(|node| (&node.key, &mut node.val))
     }
 
     fn get_left_son_id(&self, node_id: NodeId) -> Option<NodeId> {
@@ -202,43 +463,44 @@ where
         self.root_id
     }
 
-    fn get_left_son_mut(&mut self, node_id: NodeId) -> Option<RefMut<Value>> {
+    fn get_left_son_mut(&mut self, node_id: NodeId) -> Option<&mut Value> {
         self.get_by_id_mut(self.get_left_son_id(node_id)?)
     }
 
-    fn get_right_son_mut(&mut self, node_id: NodeId) -> Option<RefMut<Value>> {
+    fn get_right_son_mut(&mut self, node_id: NodeId) -> Option<&mut Value> {
         self.get_by_id_mut(self.get_right_son_id(node_id)?)
     }
 
-    fn get_root_mut(&mut self) -> Option<RefMut<Value>> {
-        self.array.first_mut().map(|node| node.val.borrow_mut())
+    fn get_root_mut(&mut self) -> Option<&mut Value> {
+        self.get_by_id_mut(self.root_id?)
     }
 
     fn modify<F>(&mut self, node_id: NodeId, mut modifier: F)
     where
-        F: FnMut(RefMut<Value>),
+        F: FnMut(&mut Value),
     {
         if let Some(node) = self.get_node_by_id_mut(node_id) {
-            modifier(node.val.borrow_mut());
+            modifier(&mut node.val);
         }
     }
 }
 
-impl<Key, Value> ParentifiedTree<Key, Value, NodeId> for ArrayBST<Key, Value>
+impl<Key, Value, Observer> ParentifiedTree<Key, Value, NodeId> for ArrayBST<Key, Value, Observer>
 where
     Key: Ord,
     Value: Clone,
+    Observer: TreeObserver<Key>,
 {
     fn get_parent_id(&self, node_id: NodeId) -> Option<NodeId> {
         self.get_node_by_id(node_id).and_then(|node| node.parent_id)
     }
 
-    fn get_parent(&mut self, node_id: NodeId) -> Option<Ref<Value>> {
+    fn get_parent(&mut self, node_id: NodeId) -> Option<&Value> {
         self.get_parent_id(node_id)
             .and_then(|parent_id| self.get_by_id(parent_id))
     }
 
-    fn get_parent_mut(&mut self, node_id: NodeId) -> Option<RefMut<Value>> {
+    fn get_parent_mut(&mut self, node_id: NodeId) -> Option<&mut Value> {
         self.get_parent_id(node_id)
             .and_then(|parent_id| self.get_by_id_mut(parent_id))
     }
@@ -257,12 +519,12 @@ where
             .and_then(|node| node.right_son_id)
     }
 
-    fn get_left_uncle_mut(&mut self, node_id: NodeId) -> Option<RefMut<Value>> {
+    fn get_left_uncle_mut(&mut self, node_id: NodeId) -> Option<&mut Value> {
         self.get_left_uncle_id(node_id)
             .and_then(|uncle_id| self.get_by_id_mut(uncle_id))
     }
 
-    fn get_right_uncle_mut(&mut self, node_id: NodeId) -> Option<RefMut<Value>> {
+    fn get_right_uncle_mut(&mut self, node_id: NodeId) -> Option<&mut Value> {
         self.get_right_uncle_id(node_id)
             .and_then(|uncle_id| self.get_by_id_mut(uncle_id))
     }
@@ -282,13 +544,14 @@ where
     }
 }
 
-impl<Key, Value> RotatableTree<Key, Value, NodeId> for ArrayBST<Key, Value>
+impl<Key, Value, Observer> RotatableTree<Key, Value, NodeId> for ArrayBST<Key, Value, Observer>
 where
     Key: Ord,
     Value: Clone,
+    Observer: TreeObserver<Key>,
 {
     fn right_rotate(&mut self, node_id: NodeId) {
-        println!("RIGHT ROTATION");
+        self.notify(node_id, Event::RightRotation);
 
         let son_id = self.get_left_son_id(node_id).expect("Son not found");
         let sons_right_son = self
@@ -310,6 +573,10 @@ where
             son.right_son_id = Some(node_id);
         }
 
+        if let Some(sons_right_son) = sons_right_son.and_then(|id| self.get_node_by_id_mut(id)) {
+            sons_right_son.parent_id = Some(node_id);
+        }
+
         if let Some(node_parent_id) = node_parent_id
             && let Some(parent) = self.get_node_by_id_mut(node_parent_id)
         {
@@ -321,10 +588,13 @@ where
         } else {
             self.root_id = Some(son_id);
         }
+
+        self.update_size(node_id);
+        self.update_size(son_id);
     }
 
     fn left_rotate(&mut self, node_id: NodeId) {
-        println!("LEFT ROTATION");
+        self.notify(node_id, Event::LeftRotation);
 
         let son_id = self.get_right_son_id(node_id).expect("Son not found");
         let sons_left_son_id = self
@@ -346,6 +616,10 @@ where
             son.left_son_id = Some(node_id);
         }
 
+        if let Some(sons_left_son) = sons_left_son_id.and_then(|id| self.get_node_by_id_mut(id)) {
+            sons_left_son.parent_id = Some(node_id);
+        }
+
         if let Some(node_parent_id) = node_parent_id
             && let Some(parent) = self.get_node_by_id_mut(node_parent_id)
         {
@@ -357,12 +631,171 @@ where
         } else {
             self.root_id = Some(son_id);
         }
+
+        self.update_size(node_id);
+        self.update_size(son_id);
+    }
+}
+
+impl<Key, Value, Observer> ObservableTree<Key, NodeId> for ArrayBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
+{
+    type Observer = Observer;
+
+    fn with_observer(observer: Observer) -> Self {
+        Self {
+            array: Default::default(),
+            free_slots: Default::default(),
+            root_id: None,
+            observer,
+        }
+    }
+
+    fn observer(&self) -> &Observer {
+        &self.observer
+    }
+
+    fn observer_mut(&mut self) -> &mut Observer {
+        &mut self.observer
+    }
+
+    fn notify(&mut self, node_id: NodeId, event: Event) {
+        if let Some(node) = self
+            .array
+            .get(node_id.node_ndx)
+            .and_then(|slot| slot.node.as_ref())
+            .filter(|node| node.id == node_id)
+        {
+            self.observer.on_event(event, &node.key);
+        }
+    }
+}
+
+impl<Key, Value, Observer> OrderedTree<Key, Value> for ArrayBST<Key, Value, Observer>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
+{
+    fn min(&self) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_min_id(self, self.root_id?))
+    }
+
+    fn max(&self) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_max_id(self, self.root_id?))
+    }
+
+    fn floor(&self, key: &Key) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_floor_id(self, key, true)?)
+    }
+
+    fn ceiling(&self, key: &Key) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_ceiling_id(self, key, true)?)
+    }
+
+    fn successor(&self, key: &Key) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_ceiling_id(self, key, false)?)
+    }
+
+    fn predecessor(&self, key: &Key) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_floor_id(self, key, false)?)
+    }
+
+    fn range<R>(&self, range: R) -> impl Iterator<Item = (Key, Value)>
+    where
+        R: RangeBounds<Key>,
+    {
+        RangeIter::new(self, range)
+    }
+
+    fn rank(&self, key: &Key) -> usize {
+        ordered_tree::get_rank(self, key)
+    }
+
+    fn select(&self, rank: usize) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_select_id(self, rank)?)
+    }
+}
+
+impl<Key, Value, Observer> TraversableTree<Key, Value, NodeId> for ArrayBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
+{
+}
+
+impl<Key, Value, Observer> BulkLoadableTree<Key, Value> for ArrayBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key> + Default,
+{
+    fn from_sorted_iter<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (Key, Value)>,
+    {
+        let entries = ordered_tree::collect_sorted(entries);
+        let len = entries.len();
+
+        let mut tree = Self::default();
+        tree.array.reserve(len);
+        tree.root_id = tree.build_balanced(&mut entries.into_iter(), len);
+        tree
+    }
+
+    fn rebuild_balanced(&mut self) {
+        let entries = traversal::collect_in_order_ids(self)
+            .into_iter()
+            .filter_map(|node_id| self.free_node(node_id))
+            .map(|node| (node.key, node.val))
+            .collect::<Vec<_>>();
+
+        let len = entries.len();
+        self.root_id = self.build_balanced(&mut entries.into_iter(), len);
+    }
+}
+
+impl<'a, Key, Value, Observer> IntoIterator for &'a ArrayBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
+{
+    type Item = (&'a Key, &'a Value);
+    type IntoIter =
+        Traverse<'a, Key, Value, NodeId, ArrayBST<Key, Value, Observer>, InOrder<NodeId>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.in_order()
+    }
+}
+
+impl<Key, Value, Observer> IntoIterator for ArrayBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
+{
+    type Item = (Key, Value);
+    type IntoIter = std::vec::IntoIter<(Key, Value)>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        traversal::collect_in_order_ids(&self)
+            .into_iter()
+            .filter_map(|node_id| self.free_node(node_id))
+            .map(|node| (node.key, node.val))
+            .collect::<Vec<_>>()
+            .into_iter()
     }
 }
 
 fn stringify_subtree<Key, Value>(
     node_id: NodeId,
-    array: &Vec<Node<Key, Value>>,
+    array: &[Slot<Key, Value>],
     prefix: String,
     is_left: bool,
     depth: usize,
@@ -371,17 +804,19 @@ where
     Key: Ord + std::fmt::Display,
     Value: Clone + std::fmt::Display,
 {
-    if depth > 100 || node_id.node_ndx >= array.len() {
+    let Some(node) = array
+        .get(node_id.node_ndx)
+        .and_then(|slot| slot.node.as_ref())
+        .filter(|_| depth <= 100)
+    else {
         return format!("{}{} NIL\n", prefix, if is_left { "├──" } else { "└──" });
-    }
-
-    let node = &array[node_id.node_ndx];
+    };
     let mut result = format!(
         "{}{} {} -> {}\n",
         prefix,
         if is_left { "├──" } else { "└──" },
         node.key,
-        node.val.bor
+        node.val
     );
 
     let child_prefix = format!("{}{}", prefix, if is_left { "│   " } else { "    " });
@@ -413,10 +848,11 @@ where
     result
 }
 
-impl<Key, Value> std::fmt::Display for ArrayBST<Key, Value>
+impl<Key, Value, Observer> std::fmt::Display for ArrayBST<Key, Value, Observer>
 where
     Key: Ord + std::fmt::Display,
     Value: Clone + std::fmt::Display,
+    Observer: TreeObserver<Key>,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         if let Some(root_id) = self.get_root_id() {
@@ -431,10 +867,64 @@ where
     }
 }
 
+impl<Key, Value, Observer> ArrayBST<Key, Value, Observer>
+where
+    Key: Ord + std::fmt::Display,
+    Value: Clone + std::fmt::Display,
+    Observer: TreeObserver<Key>,
+{
+    pub fn to_dot(&self) -> String {
+        export::to_dot(self, |value| (value.to_string(), ""))
+    }
+
+    pub fn to_json(&self) -> String {
+        export::to_json(self, |value| vec![("value", value.to_string())])
+    }
+}
+
+impl<Key, Value, Observer> ArrayBST<Key, Value, Observer>
+where
+    Key: Ord + std::fmt::Display + FromStr,
+    Value: Clone + FromStr,
+    Observer: TreeObserver<Key> + Default,
+{
+    pub fn from_json(json: &str) -> Result<Self, LoadError> {
+        export::from_json(json, |fields| fields.parse("value"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::array_bst::ArrayBST;
-    use crate::traits::{NodeIdentifiableTree, ParentifiedTree, RotatableTree, Tree};
+    use crate::array_bst::{ArrayBST, NodeId};
+    use crate::test_utils::{XorShift64, check_bulk_load, check_unsorted_bulk_load};
+    use crate::traits::{NodeIdentifiableTree, RotatableTree, Tree};
+    use std::collections::BTreeMap;
+
+    fn check_subtree(
+        tree: &ArrayBST<i32, i32>,
+        node_id: NodeId,
+        parent_id: Option<NodeId>,
+        lower: Option<i32>,
+        upper: Option<i32>,
+    ) -> usize {
+        let node = tree.get_node_by_id(node_id).expect("Node not found");
+
+        assert_eq!(node.id, node_id);
+        assert_eq!(node.parent_id, parent_id);
+        assert!(lower.is_none_or(|lower| node.key > lower));
+        assert!(upper.is_none_or(|upper| node.key < upper));
+
+        1 + node.left_son_id.map_or(0, |left_son_id| {
+            check_subtree(tree, left_son_id, Some(node_id), lower, Some(node.key))
+        }) + node.right_son_id.map_or(0, |right_son_id| {
+            check_subtree(tree, right_son_id, Some(node_id), Some(node.key), upper)
+        })
+    }
+
+    fn check_bst(tree: &ArrayBST<i32, i32>) -> usize {
+        tree.get_root_id()
+            .map_or(0, |root_id| check_subtree(tree, root_id, None, None, None))
+    }
 
     #[test]
     fn test_binary_tree() {
@@ -472,7 +962,7 @@ mod tests {
 
     #[test]
     fn test_rotations() {
-        let mut tree = ArrayBST::default();
+        let mut tree = ArrayBST::new();
         let id = tree.insert_and_get_id(5, 0);
         tree.insert(3, 0);
         tree.insert(1, 0);
@@ -480,4 +970,136 @@ mod tests {
         tree.insert(6, 0);
         tree.left_rotate(id);
     }
+
+    #[test]
+    fn test_delete() {
+        let mut tree = ArrayBST::<i32, i32>::default();
+        for key in [50, 30, 70, 20, 40, 60, 80, 35, 45, 65] {
+            tree.insert(key, key * 10);
+        }
+
+        assert_eq!(tree.delete(&20), Some(200));
+        assert_eq!(tree.delete(&60), Some(600));
+        assert_eq!(tree.delete(&30), Some(300));
+        assert_eq!(tree.delete(&50), Some(500));
+        assert_eq!(tree.delete(&50), None);
+        assert_eq!(check_bst(&tree), 6);
+
+        for key in [35, 40, 45, 65, 70, 80] {
+            assert_eq!(tree.get(&key), Some(key * 10));
+        }
+
+        let id = tree.get_id(&35).unwrap();
+        assert_eq!(tree.delete_by_id(id), Some(350));
+        assert_eq!(tree.delete_by_id(id), None);
+        assert_eq!(tree.get_id(&35), None);
+        assert_eq!(check_bst(&tree), 5);
+    }
+
+    #[test]
+    fn test_random_insert_delete() {
+        let mut random = XorShift64::new(0xB57);
+        let mut tree = ArrayBST::<i32, i32>::default();
+        let mut model = BTreeMap::new();
+
+        for step in 0..2000 {
+            let key = random.next_below(200) as i32;
+
+            if random.next_below(3) == 0 {
+                assert_eq!(tree.delete(&key), model.remove(&key));
+            } else {
+                tree.insert(key, step);
+                model.insert(key, step);
+            }
+
+            assert_eq!(check_bst(&tree), model.len());
+        }
+
+        for key in 0..200 {
+            assert_eq!(tree.get(&key), model.get(&key).copied());
+        }
+    }
+
+    #[test]
+    fn test_slot_reuse() {
+        let mut tree = ArrayBST::<i32, i32>::default();
+        let ids = (0..100).map(|key| tree.insert_and_get_id(key, key)).collect::<Vec<_>>();
+
+        for key in (0..100).step_by(2) {
+            assert_eq!(tree.delete(&key), Some(key));
+        }
+        assert_eq!(tree.size(), 50);
+
+        for key in 100..150 {
+            tree.insert(key, key);
+        }
+        assert_eq!(tree.array.len(), 100);
+        assert_eq!(tree.size(), 100);
+        assert_eq!(check_bst(&tree), 100);
+
+        for (key, id) in ids.into_iter().enumerate() {
+            if key % 2 == 0 {
+                assert!(tree.get_by_id(id).is_none());
+                assert_eq!(tree.delete_by_id(id), None);
+            } else {
+                assert_eq!(*tree.get_by_id(id).unwrap(), key as i32);
+            }
+        }
+        assert_eq!(check_bst(&tree), 100);
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut random = XorShift64::new(0xC0);
+        let mut tree = ArrayBST::<i32, i32>::default();
+        let mut model = BTreeMap::new();
+
+        for step in 0..1000 {
+            let key = random.next_below(100) as i32;
+            if random.next_below(2) == 0 {
+                tree.delete(&key);
+                model.remove(&key);
+            } else {
+                tree.insert(key, step);
+                model.insert(key, step);
+            }
+        }
+
+        let root_id = tree.get_root_id().unwrap();
+        tree.left_rotate(root_id);
+        let root_key = tree.get_root_node().unwrap().key;
+        assert_eq!(tree.get_root_mut().map(|val| *val), model.get(&root_key).copied());
+
+        let old_ids = model.keys().map(|key| tree.get_id(key).unwrap()).collect::<Vec<_>>();
+        tree.compact();
+
+        assert_eq!(tree.array.len(), model.len());
+        assert_eq!(tree.get_root_id().unwrap().node_ndx, 0);
+        assert_eq!(check_bst(&tree), model.len());
+        assert!(old_ids.into_iter().all(|id| tree.get_by_id(id).is_none()));
+
+        for (ndx, slot) in tree.array.iter().enumerate().skip(1) {
+            let parent_id = slot.node.as_ref().unwrap().parent_id.unwrap();
+            assert!(parent_id.node_ndx < ndx);
+        }
+
+        for (key, val) in &model {
+            assert_eq!(tree.get(key), Some(*val));
+        }
+
+        tree.insert(1000, 1000);
+        assert_eq!(tree.array.len(), model.len() + 1);
+    }
+
+    #[test]
+    fn test_bulk_load() {
+        check_bulk_load(check_bst);
+    }
+
+    #[test]
+    #[should_panic(expected = "Keys must be strictly increasing")]
+    fn test_from_unsorted_iter() {
+        check_unsorted_bulk_load::<ArrayBST<i32, i32>>();
+    }
+
 }
@@ -0,0 +1,409 @@
+use crate::traits::{NodeIdentifiableTree, OrderedTree, ParentifiedTree, RotatableTree, Tree};
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
+
+#[derive(Clone)]
+pub struct Data<Value>
+where
+    Value: Clone,
+{
+    value: Value,
+    height: usize,
+}
+
+impl<Value> std::fmt::Display for Data<Value>
+where
+    Value: Clone + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "[{} | h={}]", self.value, self.height)
+    }
+}
+
+fn strip_height<Key, Value>((key, data): (Key, Data<Value>)) -> (Key, Value)
+where
+    Value: Clone,
+{
+    (key, data.value)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Violation<NodeId> {
+    WrongHeight(NodeId),
+    Unbalanced(NodeId),
+    BrokenParentLink(NodeId),
+}
+
+impl<NodeId> std::fmt::Display for Violation<NodeId>
+where
+    NodeId: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::WrongHeight(node_id) => write!(f, "node {:?} stores a wrong height", node_id),
+            Self::Unbalanced(node_id) => {
+                write!(
+                    f,
+                    "subtrees of {:?} differ in height by more than one",
+                    node_id
+                )
+            }
+            Self::BrokenParentLink(node_id) => {
+                write!(f, "node {:?} has a wrong parent link", node_id)
+            }
+        }
+    }
+}
+
+pub struct AvlTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>,
+{
+    bst: ImplTree,
+    _phantom_key: PhantomData<Key>,
+    _phantom_value: PhantomData<Value>,
+    _phantom_node_id: PhantomData<NodeId>,
+}
+
+impl<Key, Value, ImplTree, NodeId> Default for AvlTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>,
+{
+    fn default() -> Self {
+        Self {
+            bst: Default::default(),
+            _phantom_key: Default::default(),
+            _phantom_value: Default::default(),
+            _phantom_node_id: Default::default(),
+        }
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> AvlTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>,
+{
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn get_height(&self, node_id: Option<NodeId>) -> usize {
+        node_id
+            .and_then(|node_id| self.bst.get_by_id(node_id).map(|data| data.height))
+            .unwrap_or(0)
+    }
+
+    fn get_sons_heights(&self, node_id: &NodeId) -> (usize, usize) {
+        (
+            self.get_height(self.bst.get_left_son_id(node_id.clone())),
+            self.get_height(self.bst.get_right_son_id(node_id.clone())),
+        )
+    }
+
+    fn update_height(&mut self, node_id: &NodeId) {
+        let (left_height, right_height) = self.get_sons_heights(node_id);
+        self.bst.modify(node_id.clone(), |data| {
+            data.height = left_height.max(right_height) + 1
+        });
+    }
+
+    fn right_rotate(&mut self, node_id: NodeId) {
+        self.bst.right_rotate(node_id.clone());
+        self.update_height(&node_id);
+
+        let new_root_id = self.bst.get_parent_id(node_id).expect("Parent not found");
+        self.update_height(&new_root_id);
+    }
+
+    fn left_rotate(&mut self, node_id: NodeId) {
+        self.bst.left_rotate(node_id.clone());
+        self.update_height(&node_id);
+
+        let new_root_id = self.bst.get_parent_id(node_id).expect("Parent not found");
+        self.update_height(&new_root_id);
+    }
+
+    fn rebalance(&mut self, node_id: NodeId) -> NodeId {
+        let (left_height, right_height) = self.get_sons_heights(&node_id);
+
+        if left_height > right_height + 1 {
+            let left_son_id = self
+                .bst
+                .get_left_son_id(node_id.clone())
+                .expect("Left son not found");
+            let (left_left_height, left_right_height) = self.get_sons_heights(&left_son_id);
+            if left_right_height > left_left_height {
+                self.left_rotate(left_son_id);
+            }
+
+            self.right_rotate(node_id.clone());
+        } else if right_height > left_height + 1 {
+            let right_son_id = self
+                .bst
+                .get_right_son_id(node_id.clone())
+                .expect("Right son not found");
+            let (right_left_height, right_right_height) = self.get_sons_heights(&right_son_id);
+            if right_left_height > right_right_height {
+                self.right_rotate(right_son_id);
+            }
+
+            self.left_rotate(node_id.clone());
+        } else {
+            self.update_height(&node_id);
+            return node_id;
+        }
+
+        self.bst.get_parent_id(node_id).expect("Parent not found")
+    }
+
+    fn retrace(&mut self, mut maybe_node_id: Option<NodeId>) {
+        while let Some(node_id) = maybe_node_id {
+            let subtree_root_id = self.rebalance(node_id);
+            maybe_node_id = self.bst.get_parent_id(subtree_root_id);
+        }
+    }
+
+    pub fn validate(&self) -> Result<usize, Violation<NodeId>> {
+        let Some(root_id) = self.bst.get_root_id() else {
+            return Ok(0);
+        };
+
+        if self.bst.get_parent_id(root_id.clone()).is_some() {
+            return Err(Violation::BrokenParentLink(root_id));
+        }
+
+        self.validate_subtree(root_id)
+    }
+
+    fn validate_subtree(&self, node_id: NodeId) -> Result<usize, Violation<NodeId>> {
+        let mut heights = [0; 2];
+
+        for (height, son_id) in heights.iter_mut().zip([
+            self.bst.get_left_son_id(node_id.clone()),
+            self.bst.get_right_son_id(node_id.clone()),
+        ]) {
+            let Some(son_id) = son_id else {
+                continue;
+            };
+
+            if self.bst.get_parent_id(son_id.clone()).as_ref() != Some(&node_id) {
+                return Err(Violation::BrokenParentLink(son_id));
+            }
+
+            *height = self.validate_subtree(son_id)?;
+        }
+
+        if heights[0].abs_diff(heights[1]) > 1 {
+            return Err(Violation::Unbalanced(node_id));
+        }
+
+        let height = heights[0].max(heights[1]) + 1;
+        if self.get_height(Some(node_id.clone())) != height {
+            return Err(Violation::WrongHeight(node_id));
+        }
+
+        Ok(height)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> Tree<Key, Value> for AvlTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>,
+{
+    fn insert(&mut self, key: Key, val: Value) {
+        if let Some(node_id) = self.bst.get_id(&key) {
+            let height = self.get_height(Some(node_id));
+            self.bst.insert_and_get_id(key, Data { value: val, height });
+            return;
+        }
+
+        let node_id = self.bst.insert_and_get_id(
+            key,
+            Data {
+                value: val,
+                height: 1,
+            },
+        );
+
+        let parent_id = self.bst.get_parent_id(node_id);
+        self.retrace(parent_id);
+    }
+
+    fn get(&self, key: &Key) -> Option<Value> {
+        self.bst.get(key).map(|x| x.value)
+    }
+
+    fn delete(&mut self, key: &Key) -> Option<Value> {
+        let z_id = self.bst.get_id(key)?;
+
+        let lowest_changed_id = match (
+            self.bst.get_left_son_id(z_id.clone()),
+            self.bst.get_right_son_id(z_id.clone()),
+        ) {
+            (Some(_), Some(right_son_id)) => {
+                let mut y_id = right_son_id;
+                while let Some(left_son_id) = self.bst.get_left_son_id(y_id.clone()) {
+                    y_id = left_son_id;
+                }
+
+                let y_parent_id = self.bst.get_parent_id(y_id.clone());
+                if y_parent_id.as_ref() == Some(&z_id) {
+                    Some(y_id)
+                } else {
+                    y_parent_id
+                }
+            }
+            _ => self.bst.get_parent_id(z_id.clone()),
+        };
+
+        let removed = self.bst.delete_by_id(z_id)?;
+        self.retrace(lowest_changed_id);
+
+        Some(removed.value)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> OrderedTree<Key, Value> for AvlTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + OrderedTree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>,
+{
+    fn min(&self) -> Option<(Key, Value)> {
+        self.bst.min().map(strip_height)
+    }
+
+    fn max(&self) -> Option<(Key, Value)> {
+        self.bst.max().map(strip_height)
+    }
+
+    fn floor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.floor(key).map(strip_height)
+    }
+
+    fn ceiling(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.ceiling(key).map(strip_height)
+    }
+
+    fn successor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.successor(key).map(strip_height)
+    }
+
+    fn predecessor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.predecessor(key).map(strip_height)
+    }
+
+    fn range<R>(&self, range: R) -> impl Iterator<Item = (Key, Value)>
+    where
+        R: RangeBounds<Key>,
+    {
+        self.bst.range(range).map(strip_height)
+    }
+
+    fn rank(&self, key: &Key) -> usize {
+        self.bst.rank(key)
+    }
+
+    fn select(&self, rank: usize) -> Option<(Key, Value)> {
+        self.bst.select(rank).map(strip_height)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> std::fmt::Display for AvlTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord + std::fmt::Display,
+    Value: Clone + std::fmt::Display,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.bst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{check_random_insert_delete, check_sequential_insert};
+    use crate::traits::Tree;
+    use crate::{ArrayAvlTree, ListAvlTree};
+
+    macro_rules! avl_tree_tests {
+        ($($backend:ident: $tree:ident,)*) => {$(
+            mod $backend {
+                use super::*;
+
+                #[test]
+                fn test_insert() {
+                    let tree = check_sequential_insert($tree::<i32, u64>::validate);
+                    assert_eq!(tree.validate(), Ok(12));
+                }
+
+                #[test]
+                fn test_delete() {
+                    let mut tree = $tree::<i32, i32>::default();
+                    for key in 1..8 {
+                        tree.insert(key, key * 10);
+                    }
+
+                    assert_eq!(tree.delete(&1), Some(10));
+                    assert_eq!(tree.delete(&4), Some(40));
+                    assert_eq!(tree.delete(&4), None);
+                    assert_eq!(tree.delete(&2), Some(20));
+                    assert!(tree.validate().is_ok());
+
+                    for key in [3, 5, 6, 7] {
+                        assert_eq!(tree.get(&key), Some(key * 10));
+                    }
+                }
+
+                #[test]
+                fn test_random_insert_delete() {
+                    check_random_insert_delete($tree::<i32, u64>::validate);
+                }
+            }
+        )*};
+    }
+
+    avl_tree_tests! {
+        array_backend: ArrayAvlTree,
+        list_backend: ListAvlTree,
+    }
+}
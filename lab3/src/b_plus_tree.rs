@@ -0,0 +1,827 @@
+use crate::traits::{OrderedTree, Tree};
+use std::ops::{Bound, RangeBounds};
+
+struct LeafNode<Key, Value> {
+    keys: Vec<Key>,
+    values: Vec<Value>,
+    next_ndx: Option<usize>,
+}
+
+struct InternalNode<Key> {
+    keys: Vec<Key>,
+    children: Vec<usize>,
+    size: usize,
+}
+
+enum Node<Key, Value> {
+    Leaf(LeafNode<Key, Value>),
+    Internal(InternalNode<Key>),
+}
+
+impl<Key, Value> Node<Key, Value> {
+    fn get_keys(&self) -> &Vec<Key> {
+        match self {
+            Self::Leaf(leaf) => &leaf.keys,
+            Self::Internal(internal) => &internal.keys,
+        }
+    }
+
+    fn get_size(&self) -> usize {
+        match self {
+            Self::Leaf(leaf) => leaf.keys.len(),
+            Self::Internal(internal) => internal.size,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Violation {
+    UnsortedKeys,
+    Overflow,
+    Underflow,
+    WrongChildrenCount,
+    UnevenLeaves,
+    WrongSize,
+    BrokenLeafLink,
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::UnsortedKeys => write!(f, "keys are out of order"),
+            Self::Overflow => write!(f, "node holds more entries than the order allows"),
+            Self::Underflow => write!(f, "non-root node holds too few entries"),
+            Self::WrongChildrenCount => write!(f, "internal node has a wrong number of children"),
+            Self::UnevenLeaves => write!(f, "leaves are at different depths"),
+            Self::WrongSize => write!(f, "node stores a wrong subtree size"),
+            Self::BrokenLeafLink => write!(f, "leaf chain does not follow key order"),
+        }
+    }
+}
+
+pub struct BPlusTree<Key, Value, const ORDER: usize = 16>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    nodes: Vec<Option<Node<Key, Value>>>,
+    free_slots: Vec<usize>,
+    root_ndx: usize,
+}
+
+impl<Key, Value, const ORDER: usize> Default for BPlusTree<Key, Value, ORDER>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        const { assert!(ORDER >= 3, "B+-tree order must be at least 3") };
+
+        Self {
+            nodes: vec![Some(Node::Leaf(LeafNode {
+                keys: Vec::new(),
+                values: Vec::new(),
+                next_ndx: None,
+            }))],
+            free_slots: Vec::new(),
+            root_ndx: 0,
+        }
+    }
+}
+
+impl<Key, Value, const ORDER: usize> BPlusTree<Key, Value, ORDER>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    const MAX_ENTRIES: usize = ORDER - 1;
+    const MIN_ENTRIES: usize = (ORDER - 1) / 2;
+    const MIN_CHILDREN: usize = ORDER.div_ceil(2);
+
+    pub fn len(&self) -> usize {
+        self.get_node(self.root_ndx).get_size()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> Iter<'_, Key, Value, ORDER> {
+        Iter {
+            tree: self,
+            leaf_ndx: Some(self.get_leftmost_leaf_ndx()),
+            entry_ndx: 0,
+            end: Bound::Unbounded,
+        }
+    }
+
+    fn get_node(&self, ndx: usize) -> &Node<Key, Value> {
+        self.nodes[ndx].as_ref().expect("Node not found")
+    }
+
+    fn get_node_mut(&mut self, ndx: usize) -> &mut Node<Key, Value> {
+        self.nodes[ndx].as_mut().expect("Node not found")
+    }
+
+    fn get_leaf(&self, ndx: usize) -> &LeafNode<Key, Value> {
+        match self.get_node(ndx) {
+            Node::Leaf(leaf) => leaf,
+            Node::Internal(_) => panic!("Leaf expected"),
+        }
+    }
+
+    fn get_leaf_mut(&mut self, ndx: usize) -> &mut LeafNode<Key, Value> {
+        match self.get_node_mut(ndx) {
+            Node::Leaf(leaf) => leaf,
+            Node::Internal(_) => panic!("Leaf expected"),
+        }
+    }
+
+    fn get_internal(&self, ndx: usize) -> &InternalNode<Key> {
+        match self.get_node(ndx) {
+            Node::Internal(internal) => internal,
+            Node::Leaf(_) => panic!("Internal node expected"),
+        }
+    }
+
+    fn get_internal_mut(&mut self, ndx: usize) -> &mut InternalNode<Key> {
+        match self.get_node_mut(ndx) {
+            Node::Internal(internal) => internal,
+            Node::Leaf(_) => panic!("Internal node expected"),
+        }
+    }
+
+    fn allocate_node(&mut self, node: Node<Key, Value>) -> usize {
+        match self.free_slots.pop() {
+            Some(ndx) => {
+                self.nodes[ndx] = Some(node);
+                ndx
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn take_node(&mut self, ndx: usize) -> Node<Key, Value> {
+        self.nodes[ndx].take().expect("Node not found")
+    }
+
+    fn free_node(&mut self, ndx: usize) -> Node<Key, Value> {
+        self.free_slots.push(ndx);
+        self.take_node(ndx)
+    }
+
+    fn get_child_ndx(internal: &InternalNode<Key>, key: &Key) -> usize {
+        internal.keys.partition_point(|separator| separator <= key)
+    }
+
+    fn get_leaf_ndx(&self, key: &Key) -> usize {
+        let mut ndx = self.root_ndx;
+
+        while let Node::Internal(internal) = self.get_node(ndx) {
+            ndx = internal.children[Self::get_child_ndx(internal, key)];
+        }
+
+        ndx
+    }
+
+    fn get_leftmost_leaf_ndx(&self) -> usize {
+        let mut ndx = self.root_ndx;
+
+        while let Node::Internal(internal) = self.get_node(ndx) {
+            ndx = internal.children[0];
+        }
+
+        ndx
+    }
+
+    fn get_children_size(&self, children: &[usize]) -> usize {
+        children
+            .iter()
+            .map(|&child_ndx| self.get_node(child_ndx).get_size())
+            .sum()
+    }
+
+    fn split_leaf(&mut self, ndx: usize) -> (Key, usize) {
+        let leaf = self.get_leaf_mut(ndx);
+        let middle = leaf.keys.len() / 2;
+
+        let right = LeafNode {
+            keys: leaf.keys.split_off(middle),
+            values: leaf.values.split_off(middle),
+            next_ndx: leaf.next_ndx,
+        };
+        let separator = right.keys[0].clone();
+
+        let right_ndx = self.allocate_node(Node::Leaf(right));
+        self.get_leaf_mut(ndx).next_ndx = Some(right_ndx);
+
+        (separator, right_ndx)
+    }
+
+    fn split_internal(&mut self, ndx: usize) -> (Key, usize) {
+        let internal = self.get_internal_mut(ndx);
+        let middle = internal.keys.len() / 2;
+
+        let keys = internal.keys.split_off(middle + 1);
+        let separator = internal.keys.pop().expect("Separator not found");
+        let children = internal.children.split_off(middle + 1);
+
+        let size = self.get_children_size(&children);
+        self.get_internal_mut(ndx).size -= size;
+
+        let right_ndx = self.allocate_node(Node::Internal(InternalNode {
+            keys,
+            children,
+            size,
+        }));
+
+        (separator, right_ndx)
+    }
+
+    fn insert_into(&mut self, ndx: usize, key: Key, val: Value) -> (bool, Option<(Key, usize)>) {
+        let (inserted, overflow) = match self.get_node_mut(ndx) {
+            Node::Leaf(leaf) => match leaf.keys.binary_search(&key) {
+                Ok(entry_ndx) => {
+                    leaf.values[entry_ndx] = val;
+                    return (false, None);
+                }
+                Err(entry_ndx) => {
+                    leaf.keys.insert(entry_ndx, key);
+                    leaf.values.insert(entry_ndx, val);
+                    (true, leaf.keys.len() > Self::MAX_ENTRIES)
+                }
+            },
+            Node::Internal(internal) => {
+                let child_ndx = Self::get_child_ndx(internal, &key);
+                let child = internal.children[child_ndx];
+                let (inserted, split) = self.insert_into(child, key, val);
+
+                let internal = self.get_internal_mut(ndx);
+                if inserted {
+                    internal.size += 1;
+                }
+
+                if let Some((separator, right_ndx)) = split {
+                    internal.keys.insert(child_ndx, separator);
+                    internal.children.insert(child_ndx + 1, right_ndx);
+                }
+
+                (inserted, internal.children.len() > ORDER)
+            }
+        };
+
+        if !overflow {
+            return (inserted, None);
+        }
+
+        let split = match self.get_node(ndx) {
+            Node::Leaf(_) => self.split_leaf(ndx),
+            Node::Internal(_) => self.split_internal(ndx),
+        };
+
+        (inserted, Some(split))
+    }
+
+    fn is_underflowing(&self, ndx: usize) -> bool {
+        match self.get_node(ndx) {
+            Node::Leaf(leaf) => leaf.keys.len() < Self::MIN_ENTRIES,
+            Node::Internal(internal) => internal.children.len() < Self::MIN_CHILDREN,
+        }
+    }
+
+    fn can_lend(&self, ndx: usize) -> bool {
+        match self.get_node(ndx) {
+            Node::Leaf(leaf) => leaf.keys.len() > Self::MIN_ENTRIES,
+            Node::Internal(internal) => internal.children.len() > Self::MIN_CHILDREN,
+        }
+    }
+
+    fn borrow_from_left(&mut self, ndx: usize, child_pos: usize) {
+        let children = &self.get_internal(ndx).children;
+        let (left_ndx, child_ndx) = (children[child_pos - 1], children[child_pos]);
+
+        let new_separator = match self.take_node(left_ndx) {
+            Node::Leaf(mut left) => {
+                let key = left.keys.pop().expect("Key not found");
+                let val = left.values.pop().expect("Value not found");
+                self.nodes[left_ndx] = Some(Node::Leaf(left));
+
+                let child = self.get_leaf_mut(child_ndx);
+                child.keys.insert(0, key.clone());
+                child.values.insert(0, val);
+                key
+            }
+            Node::Internal(mut left) => {
+                let key = left.keys.pop().expect("Key not found");
+                let grand_child_ndx = left.children.pop().expect("Child not found");
+                let moved_size = self.get_node(grand_child_ndx).get_size();
+                left.size -= moved_size;
+                self.nodes[left_ndx] = Some(Node::Internal(left));
+
+                let old_separator = self.get_internal(ndx).keys[child_pos - 1].clone();
+                let child = self.get_internal_mut(child_ndx);
+                child.keys.insert(0, old_separator);
+                child.children.insert(0, grand_child_ndx);
+                child.size += moved_size;
+                key
+            }
+        };
+
+        self.get_internal_mut(ndx).keys[child_pos - 1] = new_separator;
+    }
+
+    fn borrow_from_right(&mut self, ndx: usize, child_pos: usize) {
+        let children = &self.get_internal(ndx).children;
+        let (child_ndx, right_ndx) = (children[child_pos], children[child_pos + 1]);
+
+        let new_separator = match self.take_node(right_ndx) {
+            Node::Leaf(mut right) => {
+                let key = right.keys.remove(0);
+                let val = right.values.remove(0);
+                let new_separator = right.keys[0].clone();
+                self.nodes[right_ndx] = Some(Node::Leaf(right));
+
+                let child = self.get_leaf_mut(child_ndx);
+                child.keys.push(key);
+                child.values.push(val);
+                new_separator
+            }
+            Node::Internal(mut right) => {
+                let key = right.keys.remove(0);
+                let grand_child_ndx = right.children.remove(0);
+                let moved_size = self.get_node(grand_child_ndx).get_size();
+                right.size -= moved_size;
+                self.nodes[right_ndx] = Some(Node::Internal(right));
+
+                let old_separator = self.get_internal(ndx).keys[child_pos].clone();
+                let child = self.get_internal_mut(child_ndx);
+                child.keys.push(old_separator);
+                child.children.push(grand_child_ndx);
+                child.size += moved_size;
+                key
+            }
+        };
+
+        self.get_internal_mut(ndx).keys[child_pos] = new_separator;
+    }
+
+    fn merge_children(&mut self, ndx: usize, left_pos: usize) {
+        let internal = self.get_internal_mut(ndx);
+        let separator = internal.keys.remove(left_pos);
+        let right_ndx = internal.children.remove(left_pos + 1);
+        let left_ndx = internal.children[left_pos];
+
+        match (self.free_node(right_ndx), self.get_node_mut(left_ndx)) {
+            (Node::Leaf(right), Node::Leaf(left)) => {
+                left.keys.extend(right.keys);
+                left.values.extend(right.values);
+                left.next_ndx = right.next_ndx;
+            }
+            (Node::Internal(right), Node::Internal(left)) => {
+                left.keys.push(separator);
+                left.keys.extend(right.keys);
+                left.children.extend(right.children);
+                left.size += right.size;
+            }
+            _ => panic!("Siblings must be at the same depth"),
+        }
+    }
+
+    fn fix_child(&mut self, ndx: usize, child_pos: usize) {
+        let children = &self.get_internal(ndx).children;
+        let child_ndx = children[child_pos];
+        let left_ndx = child_pos.checked_sub(1).map(|pos| children[pos]);
+        let right_ndx = children.get(child_pos + 1).copied();
+
+        if !self.is_underflowing(child_ndx) {
+            return;
+        }
+
+        if left_ndx.is_some_and(|left_ndx| self.can_lend(left_ndx)) {
+            self.borrow_from_left(ndx, child_pos);
+        } else if right_ndx.is_some_and(|right_ndx| self.can_lend(right_ndx)) {
+            self.borrow_from_right(ndx, child_pos);
+        } else if left_ndx.is_some() {
+            self.merge_children(ndx, child_pos - 1);
+        } else {
+            self.merge_children(ndx, child_pos);
+        }
+    }
+
+    fn delete_from(&mut self, ndx: usize, key: &Key) -> Option<Value> {
+        match self.get_node_mut(ndx) {
+            Node::Leaf(leaf) => {
+                let entry_ndx = leaf.keys.binary_search(key).ok()?;
+                leaf.keys.remove(entry_ndx);
+                Some(leaf.values.remove(entry_ndx))
+            }
+            Node::Internal(internal) => {
+                let child_pos = Self::get_child_ndx(internal, key);
+                let child_ndx = internal.children[child_pos];
+
+                let removed = self.delete_from(child_ndx, key)?;
+                self.get_internal_mut(ndx).size -= 1;
+                self.fix_child(ndx, child_pos);
+
+                Some(removed)
+            }
+        }
+    }
+
+    fn get_floor(&self, key: &Key, inclusive: bool) -> Option<(&Key, &Value)> {
+        self.get_entry_by_rank(self.get_rank(key, inclusive).checked_sub(1)?)
+    }
+
+    fn get_rank(&self, key: &Key, inclusive: bool) -> usize {
+        let mut rank = 0;
+        let mut ndx = self.root_ndx;
+
+        loop {
+            match self.get_node(ndx) {
+                Node::Leaf(leaf) => {
+                    return rank
+                        + if inclusive {
+                            leaf.keys.partition_point(|leaf_key| leaf_key <= key)
+                        } else {
+                            leaf.keys.partition_point(|leaf_key| leaf_key < key)
+                        };
+                }
+                Node::Internal(internal) => {
+                    let child_pos = Self::get_child_ndx(internal, key);
+                    rank += self.get_children_size(&internal.children[..child_pos]);
+                    ndx = internal.children[child_pos];
+                }
+            }
+        }
+    }
+
+    fn get_entry_by_rank(&self, mut rank: usize) -> Option<(&Key, &Value)> {
+        if rank >= self.len() {
+            return None;
+        }
+
+        let mut ndx = self.root_ndx;
+
+        loop {
+            match self.get_node(ndx) {
+                Node::Leaf(leaf) => return Some((&leaf.keys[rank], &leaf.values[rank])),
+                Node::Internal(internal) => {
+                    for &child_ndx in &internal.children {
+                        let child_size = self.get_node(child_ndx).get_size();
+                        if rank < child_size {
+                            ndx = child_ndx;
+                            break;
+                        }
+
+                        rank -= child_size;
+                    }
+                }
+            }
+        }
+    }
+
+    fn get_range_start(&self, start: Bound<&Key>) -> (usize, usize) {
+        let (key, inclusive) = match start {
+            Bound::Included(key) => (key, true),
+            Bound::Excluded(key) => (key, false),
+            Bound::Unbounded => return (self.get_leftmost_leaf_ndx(), 0),
+        };
+
+        let leaf_ndx = self.get_leaf_ndx(key);
+        let leaf = self.get_leaf(leaf_ndx);
+        let entry_ndx = if inclusive {
+            leaf.keys.partition_point(|leaf_key| leaf_key < key)
+        } else {
+            leaf.keys.partition_point(|leaf_key| leaf_key <= key)
+        };
+
+        (leaf_ndx, entry_ndx)
+    }
+
+    pub fn validate(&self) -> Result<usize, Violation> {
+        let mut leaves = Vec::new();
+        let height = self.validate_subtree(self.root_ndx, None, None, true, &mut leaves)?;
+
+        let mut leaf_ndx = Some(self.get_leftmost_leaf_ndx());
+        for &expected_ndx in &leaves {
+            if leaf_ndx != Some(expected_ndx) {
+                return Err(Violation::BrokenLeafLink);
+            }
+
+            leaf_ndx = self.get_leaf(expected_ndx).next_ndx;
+        }
+
+        if leaf_ndx.is_some() {
+            return Err(Violation::BrokenLeafLink);
+        }
+
+        Ok(height)
+    }
+
+    fn validate_subtree(
+        &self,
+        ndx: usize,
+        lower_bound: Option<&Key>,
+        upper_bound: Option<&Key>,
+        is_root: bool,
+        leaves: &mut Vec<usize>,
+    ) -> Result<usize, Violation> {
+        let node = self.get_node(ndx);
+        let keys = node.get_keys();
+
+        if keys.windows(2).any(|pair| pair[0] >= pair[1])
+            || lower_bound
+                .zip(keys.first())
+                .is_some_and(|(bound, key)| key < bound)
+            || upper_bound
+                .zip(keys.last())
+                .is_some_and(|(bound, key)| key >= bound)
+        {
+            return Err(Violation::UnsortedKeys);
+        }
+
+        let internal = match node {
+            Node::Leaf(leaf) => {
+                if leaf.keys.len() > Self::MAX_ENTRIES {
+                    return Err(Violation::Overflow);
+                }
+
+                if !is_root && leaf.keys.len() < Self::MIN_ENTRIES {
+                    return Err(Violation::Underflow);
+                }
+
+                leaves.push(ndx);
+                return Ok(1);
+            }
+            Node::Internal(internal) => internal,
+        };
+
+        if internal.children.len() > ORDER {
+            return Err(Violation::Overflow);
+        }
+
+        if internal.children.len() < if is_root { 2 } else { Self::MIN_CHILDREN } {
+            return Err(Violation::Underflow);
+        }
+
+        if internal.children.len() != internal.keys.len() + 1 {
+            return Err(Violation::WrongChildrenCount);
+        }
+
+        let mut height = None;
+        for (child_pos, &child_ndx) in internal.children.iter().enumerate() {
+            let child_height = self.validate_subtree(
+                child_ndx,
+                child_pos
+                    .checked_sub(1)
+                    .map(|pos| &internal.keys[pos])
+                    .or(lower_bound),
+                internal.keys.get(child_pos).or(upper_bound),
+                false,
+                leaves,
+            )?;
+
+            if height
+                .replace(child_height)
+                .is_some_and(|height| height != child_height)
+            {
+                return Err(Violation::UnevenLeaves);
+            }
+        }
+
+        if internal.size != self.get_children_size(&internal.children) {
+            return Err(Violation::WrongSize);
+        }
+
+        Ok(height.unwrap_or(0) + 1)
+    }
+}
+
+impl<Key, Value, const ORDER: usize> Tree<Key, Value> for BPlusTree<Key, Value, ORDER>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    fn insert(&mut self, key: Key, val: Value) {
+        let (_, split) = self.insert_into(self.root_ndx, key, val);
+
+        if let Some((separator, right_ndx)) = split {
+            let size = self.get_children_size(&[self.root_ndx, right_ndx]);
+            self.root_ndx = self.allocate_node(Node::Internal(InternalNode {
+                keys: vec![separator],
+                children: vec![self.root_ndx, right_ndx],
+                size,
+            }));
+        }
+    }
+
+    fn get(&self, key: &Key) -> Option<Value> {
+        let leaf = self.get_leaf(self.get_leaf_ndx(key));
+        let entry_ndx = leaf.keys.binary_search(key).ok()?;
+
+        Some(leaf.values[entry_ndx].clone())
+    }
+
+    fn delete(&mut self, key: &Key) -> Option<Value> {
+        let removed = self.delete_from(self.root_ndx, key)?;
+
+        if let Node::Internal(root) = self.get_node(self.root_ndx)
+            && root.children.len() == 1
+        {
+            let new_root_ndx = root.children[0];
+            self.free_node(self.root_ndx);
+            self.root_ndx = new_root_ndx;
+        }
+
+        Some(removed)
+    }
+}
+
+impl<Key, Value, const ORDER: usize> OrderedTree<Key, Value> for BPlusTree<Key, Value, ORDER>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    fn min(&self) -> Option<(Key, Value)> {
+        self.iter()
+            .next()
+            .map(|(key, val)| (key.clone(), val.clone()))
+    }
+
+    fn max(&self) -> Option<(Key, Value)> {
+        self.get_entry_by_rank(self.len().checked_sub(1)?)
+            .map(|(key, val)| (key.clone(), val.clone()))
+    }
+
+    fn floor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.get_floor(key, true)
+            .map(|(key, val)| (key.clone(), val.clone()))
+    }
+
+    fn ceiling(&self, key: &Key) -> Option<(Key, Value)> {
+        self.range((Bound::Included(key), Bound::Unbounded)).next()
+    }
+
+    fn successor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.range((Bound::Excluded(key), Bound::Unbounded)).next()
+    }
+
+    fn predecessor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.get_floor(key, false)
+            .map(|(key, val)| (key.clone(), val.clone()))
+    }
+
+    fn range<R>(&self, range: R) -> impl Iterator<Item = (Key, Value)>
+    where
+        R: RangeBounds<Key>,
+    {
+        let (leaf_ndx, entry_ndx) = self.get_range_start(range.start_bound());
+
+        Iter {
+            tree: self,
+            leaf_ndx: Some(leaf_ndx),
+            entry_ndx,
+            end: range.end_bound().cloned(),
+        }
+        .map(|(key, val)| (key.clone(), val.clone()))
+    }
+
+    fn rank(&self, key: &Key) -> usize {
+        self.get_rank(key, false)
+    }
+
+    fn select(&self, rank: usize) -> Option<(Key, Value)> {
+        self.get_entry_by_rank(rank)
+            .map(|(key, val)| (key.clone(), val.clone()))
+    }
+}
+
+pub struct Iter<'a, Key, Value, const ORDER: usize>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    tree: &'a BPlusTree<Key, Value, ORDER>,
+    leaf_ndx: Option<usize>,
+    entry_ndx: usize,
+    end: Bound<Key>,
+}
+
+impl<'a, Key, Value, const ORDER: usize> Iterator for Iter<'a, Key, Value, ORDER>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    type Item = (&'a Key, &'a Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let leaf = self.tree.get_leaf(self.leaf_ndx?);
+
+            if self.entry_ndx == leaf.keys.len() {
+                self.leaf_ndx = leaf.next_ndx;
+                self.entry_ndx = 0;
+                continue;
+            }
+
+            let key = &leaf.keys[self.entry_ndx];
+            let past_end = match &self.end {
+                Bound::Included(end) => key > end,
+                Bound::Excluded(end) => key >= end,
+                Bound::Unbounded => false,
+            };
+
+            if past_end {
+                self.leaf_ndx = None;
+                return None;
+            }
+
+            self.entry_ndx += 1;
+            return Some((key, &leaf.values[self.entry_ndx - 1]));
+        }
+    }
+}
+
+impl<'a, Key, Value, const ORDER: usize> IntoIterator for &'a BPlusTree<Key, Value, ORDER>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    type Item = (&'a Key, &'a Value);
+    type IntoIter = Iter<'a, Key, Value, ORDER>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::check_random_insert_delete;
+
+    #[test]
+    fn test_insert() {
+        let mut tree = BPlusTree::<i32, i32, 3>::default();
+        assert_eq!(tree.validate(), Ok(1));
+
+        for key in 0..1024 {
+            tree.insert(key, key * 10);
+            assert!(tree.validate().is_ok());
+        }
+
+        assert_eq!(tree.len(), 1024);
+        assert!((6..=11).contains(&tree.validate().unwrap()));
+
+        tree.insert(512, 0);
+        assert_eq!(tree.len(), 1024);
+        assert_eq!(tree.get(&512), Some(0));
+        assert_eq!(tree.get(&1024), None);
+
+        let keys = tree.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+        assert_eq!(keys, (0..1024).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_delete() {
+        let mut tree = BPlusTree::<i32, i32, 4>::default();
+        for key in 1..64 {
+            tree.insert(key, key * 10);
+        }
+
+        for key in (1..64).step_by(3) {
+            assert_eq!(tree.delete(&key), Some(key * 10));
+            assert_eq!(tree.delete(&key), None);
+            assert!(tree.validate().is_ok());
+        }
+
+        assert_eq!(tree.len(), 42);
+        assert!(
+            tree.iter()
+                .all(|(key, val)| key % 3 != 1 && *val == key * 10)
+        );
+
+        for key in 1..64 {
+            tree.delete(&key);
+        }
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.validate(), Ok(1));
+        assert_eq!(tree.nodes.len() - tree.free_slots.len(), 1);
+    }
+
+    #[test]
+    fn test_random_insert_delete() {
+        check_random_insert_delete(BPlusTree::<i32, u64, 3>::validate);
+        check_random_insert_delete(BPlusTree::<i32, u64, 6>::validate);
+        check_random_insert_delete(BPlusTree::<i32, u64>::validate);
+    }
+}
@@ -0,0 +1,676 @@
+use crate::traits::{OrderedTree, Tree};
+use std::ops::{Bound, RangeBounds};
+
+struct Node<Key, Value> {
+    keys: Vec<Key>,
+    values: Vec<Value>,
+    children: Vec<Node<Key, Value>>,
+    size: usize,
+}
+
+impl<Key, Value> Default for Node<Key, Value> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            keys: Vec::new(),
+            values: Vec::new(),
+            children: Vec::new(),
+            size: 0,
+        }
+    }
+}
+
+impl<Key, Value> Node<Key, Value> {
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    fn get_entry(&self, ndx: usize) -> (&Key, &Value) {
+        (&self.keys[ndx], &self.values[ndx])
+    }
+
+    fn get_children_size(&self, range: impl RangeBounds<usize>) -> usize {
+        if self.is_leaf() {
+            return 0;
+        }
+
+        self.children[(range.start_bound().cloned(), range.end_bound().cloned())]
+            .iter()
+            .map(|child| child.size)
+            .sum()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Violation {
+    UnsortedKeys,
+    Overflow,
+    Underflow,
+    WrongChildrenCount,
+    UnevenLeaves,
+    WrongSize,
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::UnsortedKeys => write!(f, "keys are out of order"),
+            Self::Overflow => write!(f, "node holds more keys than the order allows"),
+            Self::Underflow => write!(f, "non-root node holds too few keys"),
+            Self::WrongChildrenCount => write!(f, "internal node has a wrong number of children"),
+            Self::UnevenLeaves => write!(f, "leaves are at different depths"),
+            Self::WrongSize => write!(f, "node stores a wrong subtree size"),
+        }
+    }
+}
+
+pub struct BTree<Key, Value, const ORDER: usize = 16>
+where
+    Key: Ord,
+    Value: Clone,
+{
+    root: Node<Key, Value>,
+}
+
+impl<Key, Value, const ORDER: usize> Default for BTree<Key, Value, ORDER>
+where
+    Key: Ord,
+    Value: Clone,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        const { assert!(ORDER >= 3, "B-tree order must be at least 3") };
+
+        Self {
+            root: Default::default(),
+        }
+    }
+}
+
+impl<Key, Value, const ORDER: usize> BTree<Key, Value, ORDER>
+where
+    Key: Ord,
+    Value: Clone,
+{
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    const MAX_KEYS: usize = ORDER - 1;
+    const MIN_KEYS: usize = ORDER.div_ceil(2) - 1;
+
+    pub fn len(&self) -> usize {
+        self.root.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.size == 0
+    }
+
+    pub fn iter(&self) -> Iter<'_, Key, Value> {
+        Iter::new(&self.root, Bound::Unbounded, Bound::Unbounded)
+    }
+
+    fn split_child(node: &mut Node<Key, Value>, ndx: usize) {
+        let child = &mut node.children[ndx];
+        let middle = child.keys.len() / 2;
+
+        let mut right = Node {
+            keys: child.keys.split_off(middle + 1),
+            values: child.values.split_off(middle + 1),
+            children: if child.is_leaf() {
+                Vec::new()
+            } else {
+                child.children.split_off(middle + 1)
+            },
+            size: 0,
+        };
+        right.size = right.keys.len() + right.get_children_size(..);
+        child.size -= right.size + 1;
+
+        let key = child.keys.pop().expect("Separator not found");
+        let val = child.values.pop().expect("Separator not found");
+        node.keys.insert(ndx, key);
+        node.values.insert(ndx, val);
+        node.children.insert(ndx + 1, right);
+    }
+
+    fn insert_into(node: &mut Node<Key, Value>, key: Key, val: Value) -> Option<Value> {
+        let ndx = match node.keys.binary_search(&key) {
+            Ok(ndx) => return Some(std::mem::replace(&mut node.values[ndx], val)),
+            Err(ndx) => ndx,
+        };
+
+        if node.is_leaf() {
+            node.keys.insert(ndx, key);
+            node.values.insert(ndx, val);
+            node.size += 1;
+            return None;
+        }
+
+        let replaced = Self::insert_into(&mut node.children[ndx], key, val);
+        if replaced.is_none() {
+            node.size += 1;
+        }
+
+        if node.children[ndx].keys.len() > Self::MAX_KEYS {
+            Self::split_child(node, ndx);
+        }
+
+        replaced
+    }
+
+    fn borrow_from_left(node: &mut Node<Key, Value>, ndx: usize) {
+        let (left, right) = node.children.split_at_mut(ndx);
+        let (left, child) = (&mut left[ndx - 1], &mut right[0]);
+
+        let key = std::mem::replace(
+            &mut node.keys[ndx - 1],
+            left.keys.pop().expect("Key not found"),
+        );
+        let val = std::mem::replace(
+            &mut node.values[ndx - 1],
+            left.values.pop().expect("Value not found"),
+        );
+        child.keys.insert(0, key);
+        child.values.insert(0, val);
+
+        let mut moved_size = 1;
+        if let Some(grand_child) = left.children.pop() {
+            moved_size += grand_child.size;
+            child.children.insert(0, grand_child);
+        }
+
+        left.size -= moved_size;
+        child.size += moved_size;
+    }
+
+    fn borrow_from_right(node: &mut Node<Key, Value>, ndx: usize) {
+        let (left, right) = node.children.split_at_mut(ndx + 1);
+        let (child, right) = (&mut left[ndx], &mut right[0]);
+
+        let key = std::mem::replace(&mut node.keys[ndx], right.keys.remove(0));
+        let val = std::mem::replace(&mut node.values[ndx], right.values.remove(0));
+        child.keys.push(key);
+        child.values.push(val);
+
+        let mut moved_size = 1;
+        if !right.is_leaf() {
+            let grand_child = right.children.remove(0);
+            moved_size += grand_child.size;
+            child.children.push(grand_child);
+        }
+
+        right.size -= moved_size;
+        child.size += moved_size;
+    }
+
+    fn merge_children(node: &mut Node<Key, Value>, ndx: usize) {
+        let right = node.children.remove(ndx + 1);
+        let left = &mut node.children[ndx];
+
+        left.keys.push(node.keys.remove(ndx));
+        left.values.push(node.values.remove(ndx));
+        left.keys.extend(right.keys);
+        left.values.extend(right.values);
+        left.children.extend(right.children);
+        left.size += right.size + 1;
+    }
+
+    fn fix_child(node: &mut Node<Key, Value>, ndx: usize) {
+        if node.children[ndx].keys.len() >= Self::MIN_KEYS {
+            return;
+        }
+
+        if ndx > 0 && node.children[ndx - 1].keys.len() > Self::MIN_KEYS {
+            Self::borrow_from_left(node, ndx);
+        } else if ndx + 1 < node.children.len()
+            && node.children[ndx + 1].keys.len() > Self::MIN_KEYS
+        {
+            Self::borrow_from_right(node, ndx);
+        } else if ndx > 0 {
+            Self::merge_children(node, ndx - 1);
+        } else {
+            Self::merge_children(node, ndx);
+        }
+    }
+
+    fn delete_max(node: &mut Node<Key, Value>) -> (Key, Value) {
+        node.size -= 1;
+
+        if node.is_leaf() {
+            return (
+                node.keys.pop().expect("Key not found"),
+                node.values.pop().expect("Value not found"),
+            );
+        }
+
+        let last_ndx = node.children.len() - 1;
+        let entry = Self::delete_max(&mut node.children[last_ndx]);
+        Self::fix_child(node, last_ndx);
+
+        entry
+    }
+
+    fn delete_from(node: &mut Node<Key, Value>, key: &Key) -> Option<Value> {
+        let removed = match node.keys.binary_search(key) {
+            Ok(ndx) if node.is_leaf() => {
+                node.keys.remove(ndx);
+                node.values.remove(ndx)
+            }
+            Ok(ndx) => {
+                let (max_key, max_val) = Self::delete_max(&mut node.children[ndx]);
+                node.keys[ndx] = max_key;
+                let removed = std::mem::replace(&mut node.values[ndx], max_val);
+                Self::fix_child(node, ndx);
+                removed
+            }
+            Err(_) if node.is_leaf() => return None,
+            Err(ndx) => {
+                let removed = Self::delete_from(&mut node.children[ndx], key)?;
+                Self::fix_child(node, ndx);
+                removed
+            }
+        };
+
+        node.size -= 1;
+        Some(removed)
+    }
+
+    fn get_floor(&self, key: &Key, inclusive: bool) -> Option<(&Key, &Value)> {
+        let mut floor = None;
+        let mut node = &self.root;
+
+        loop {
+            let ndx = match node.keys.binary_search(key) {
+                Ok(ndx) if inclusive => return Some(node.get_entry(ndx)),
+                Ok(ndx) | Err(ndx) => ndx,
+            };
+
+            if ndx > 0 {
+                floor = Some(node.get_entry(ndx - 1));
+            }
+
+            if node.is_leaf() {
+                return floor;
+            }
+
+            node = &node.children[ndx];
+        }
+    }
+
+    fn get_ceiling(&self, key: &Key, inclusive: bool) -> Option<(&Key, &Value)> {
+        let mut ceiling = None;
+        let mut node = &self.root;
+
+        loop {
+            let ndx = match node.keys.binary_search(key) {
+                Ok(ndx) if inclusive => return Some(node.get_entry(ndx)),
+                Ok(ndx) => ndx + 1,
+                Err(ndx) => ndx,
+            };
+
+            if ndx < node.keys.len() {
+                ceiling = Some(node.get_entry(ndx));
+            }
+
+            if node.is_leaf() {
+                return ceiling;
+            }
+
+            node = &node.children[ndx];
+        }
+    }
+
+    pub fn validate(&self) -> Result<usize, Violation> {
+        self.validate_subtree(&self.root, None, None, true)
+    }
+
+    fn validate_subtree(
+        &self,
+        node: &Node<Key, Value>,
+        lower_bound: Option<&Key>,
+        upper_bound: Option<&Key>,
+        is_root: bool,
+    ) -> Result<usize, Violation> {
+        if node.keys.len() > Self::MAX_KEYS {
+            return Err(Violation::Overflow);
+        }
+
+        if !is_root && node.keys.len() < Self::MIN_KEYS {
+            return Err(Violation::Underflow);
+        }
+
+        if node.keys.windows(2).any(|pair| pair[0] >= pair[1])
+            || lower_bound
+                .zip(node.keys.first())
+                .is_some_and(|(bound, key)| key <= bound)
+            || upper_bound
+                .zip(node.keys.last())
+                .is_some_and(|(bound, key)| key >= bound)
+        {
+            return Err(Violation::UnsortedKeys);
+        }
+
+        if node.is_leaf() {
+            return if node.size == node.keys.len() {
+                Ok(1)
+            } else {
+                Err(Violation::WrongSize)
+            };
+        }
+
+        if node.children.len() != node.keys.len() + 1 {
+            return Err(Violation::WrongChildrenCount);
+        }
+
+        let mut height = None;
+        for (ndx, child) in node.children.iter().enumerate() {
+            let child_height = self.validate_subtree(
+                child,
+                ndx.checked_sub(1)
+                    .map(|ndx| &node.keys[ndx])
+                    .or(lower_bound),
+                node.keys.get(ndx).or(upper_bound),
+                false,
+            )?;
+
+            if height
+                .replace(child_height)
+                .is_some_and(|height| height != child_height)
+            {
+                return Err(Violation::UnevenLeaves);
+            }
+        }
+
+        if node.size != node.keys.len() + node.get_children_size(..) {
+            return Err(Violation::WrongSize);
+        }
+
+        Ok(height.unwrap_or(0) + 1)
+    }
+}
+
+impl<Key, Value, const ORDER: usize> Tree<Key, Value> for BTree<Key, Value, ORDER>
+where
+    Key: Ord,
+    Value: Clone,
+{
+    fn insert(&mut self, key: Key, val: Value) {
+        Self::insert_into(&mut self.root, key, val);
+
+        if self.root.keys.len() > Self::MAX_KEYS {
+            let old_root = std::mem::take(&mut self.root);
+            self.root.size = old_root.size;
+            self.root.children.push(old_root);
+            Self::split_child(&mut self.root, 0);
+        }
+    }
+
+    fn get(&self, key: &Key) -> Option<Value> {
+        self.get_floor(key, true)
+            .filter(|(floor_key, _)| *floor_key == key)
+            .map(|(_, val)| val.clone())
+    }
+
+    fn delete(&mut self, key: &Key) -> Option<Value> {
+        let removed = Self::delete_from(&mut self.root, key)?;
+
+        if self.root.keys.is_empty() && !self.root.is_leaf() {
+            self.root = self.root.children.pop().expect("Child not found");
+        }
+
+        Some(removed)
+    }
+}
+
+impl<Key, Value, const ORDER: usize> OrderedTree<Key, Value> for BTree<Key, Value, ORDER>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    fn min(&self) -> Option<(Key, Value)> {
+        self.iter()
+            .next()
+            .map(|(key, val)| (key.clone(), val.clone()))
+    }
+
+    fn max(&self) -> Option<(Key, Value)> {
+        let mut node = &self.root;
+        while let Some(last_child) = node.children.last() {
+            node = last_child;
+        }
+
+        Some((node.keys.last()?.clone(), node.values.last()?.clone()))
+    }
+
+    fn floor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.get_floor(key, true)
+            .map(|(key, val)| (key.clone(), val.clone()))
+    }
+
+    fn ceiling(&self, key: &Key) -> Option<(Key, Value)> {
+        self.get_ceiling(key, true)
+            .map(|(key, val)| (key.clone(), val.clone()))
+    }
+
+    fn successor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.get_ceiling(key, false)
+            .map(|(key, val)| (key.clone(), val.clone()))
+    }
+
+    fn predecessor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.get_floor(key, false)
+            .map(|(key, val)| (key.clone(), val.clone()))
+    }
+
+    fn range<R>(&self, range: R) -> impl Iterator<Item = (Key, Value)>
+    where
+        R: RangeBounds<Key>,
+    {
+        Iter::new(&self.root, range.start_bound(), range.end_bound().cloned())
+            .map(|(key, val)| (key.clone(), val.clone()))
+    }
+
+    fn rank(&self, key: &Key) -> usize {
+        let mut rank = 0;
+        let mut node = &self.root;
+
+        loop {
+            let (ndx, found) = match node.keys.binary_search(key) {
+                Ok(ndx) => (ndx, true),
+                Err(ndx) => (ndx, false),
+            };
+
+            rank += ndx + node.get_children_size(..ndx);
+
+            if node.is_leaf() {
+                return rank;
+            }
+
+            if found {
+                return rank + node.children[ndx].size;
+            }
+
+            node = &node.children[ndx];
+        }
+    }
+
+    fn select(&self, mut rank: usize) -> Option<(Key, Value)> {
+        if rank >= self.root.size {
+            return None;
+        }
+
+        let mut node = &self.root;
+
+        'descend: loop {
+            for ndx in 0..=node.keys.len() {
+                let child_size = node.get_children_size(ndx..=ndx);
+                if rank < child_size {
+                    node = &node.children[ndx];
+                    continue 'descend;
+                }
+
+                rank -= child_size;
+                if rank == 0 {
+                    return Some((node.keys[ndx].clone(), node.values[ndx].clone()));
+                }
+
+                rank -= 1;
+            }
+        }
+    }
+}
+
+pub struct Iter<'a, Key, Value> {
+    stack: Vec<(&'a Node<Key, Value>, usize)>,
+    end: Bound<Key>,
+}
+
+impl<'a, Key, Value> Iter<'a, Key, Value>
+where
+    Key: Ord,
+{
+    fn new(root: &'a Node<Key, Value>, start: Bound<&Key>, end: Bound<Key>) -> Self {
+        let mut stack = Vec::new();
+        let mut node = root;
+
+        loop {
+            let ndx = match start {
+                Bound::Included(start) => node.keys.partition_point(|key| key < start),
+                Bound::Excluded(start) => node.keys.partition_point(|key| key <= start),
+                Bound::Unbounded => 0,
+            };
+
+            stack.push((node, ndx));
+
+            if node.is_leaf() {
+                break;
+            }
+
+            node = &node.children[ndx];
+        }
+
+        Self { stack, end }
+    }
+
+    fn push_leftmost_path(&mut self, mut node: &'a Node<Key, Value>) {
+        loop {
+            self.stack.push((node, 0));
+
+            if node.is_leaf() {
+                break;
+            }
+
+            node = &node.children[0];
+        }
+    }
+}
+
+impl<'a, Key, Value> Iterator for Iter<'a, Key, Value>
+where
+    Key: Ord,
+{
+    type Item = (&'a Key, &'a Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, ndx) = self.stack.last_mut()?;
+            let node: &'a Node<Key, Value> = node;
+
+            if *ndx == node.keys.len() {
+                self.stack.pop();
+                continue;
+            }
+
+            let entry = node.get_entry(*ndx);
+            *ndx += 1;
+
+            let past_end = match &self.end {
+                Bound::Included(end) => entry.0 > end,
+                Bound::Excluded(end) => entry.0 >= end,
+                Bound::Unbounded => false,
+            };
+
+            if past_end {
+                self.stack.clear();
+                return None;
+            }
+
+            if !node.is_leaf() {
+                let ndx = *ndx;
+                self.push_leftmost_path(&node.children[ndx]);
+            }
+
+            return Some(entry);
+        }
+    }
+}
+
+impl<'a, Key, Value, const ORDER: usize> IntoIterator for &'a BTree<Key, Value, ORDER>
+where
+    Key: Ord,
+    Value: Clone,
+{
+    type Item = (&'a Key, &'a Value);
+    type IntoIter = Iter<'a, Key, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::check_random_insert_delete;
+
+    #[test]
+    fn test_insert() {
+        let mut tree = BTree::<i32, i32, 3>::default();
+        assert_eq!(tree.validate(), Ok(1));
+
+        for key in 0..1024 {
+            tree.insert(key, key * 10);
+            assert!(tree.validate().is_ok());
+        }
+
+        assert_eq!(tree.len(), 1024);
+        assert!((6..=10).contains(&tree.validate().unwrap()));
+
+        tree.insert(512, 0);
+        assert_eq!(tree.len(), 1024);
+        assert_eq!(tree.get(&512), Some(0));
+        assert_eq!(tree.get(&1024), None);
+
+        let keys = tree.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+        assert_eq!(keys, (0..1024).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_delete() {
+        let mut tree = BTree::<i32, i32, 4>::default();
+        for key in 1..64 {
+            tree.insert(key, key * 10);
+        }
+
+        for key in (1..64).step_by(3) {
+            assert_eq!(tree.delete(&key), Some(key * 10));
+            assert_eq!(tree.delete(&key), None);
+            assert!(tree.validate().is_ok());
+        }
+
+        assert_eq!(tree.len(), 42);
+        assert!(
+            tree.iter()
+                .all(|(key, val)| key % 3 != 1 && *val == key * 10)
+        );
+    }
+
+    #[test]
+    fn test_random_insert_delete() {
+        check_random_insert_delete(BTree::<i32, u64, 3>::validate);
+        check_random_insert_delete(BTree::<i32, u64, 6>::validate);
+        check_random_insert_delete(BTree::<i32, u64>::validate);
+    }
+}
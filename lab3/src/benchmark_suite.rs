@@ -0,0 +1,248 @@
+use crate::array_bst::ArrayBST;
+use crate::list_bst::ListBST;
+use crate::random::XorShift64;
+use crate::traits::Tree;
+use crate::{ArrayRedBlackTree, ListRedBlackTree};
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+pub const BIG_TREE_SIZE: usize = 10_000;
+
+pub const MIN_MEASUREMENT_TIME: Duration = Duration::from_millis(200);
+const CSV_HEADER: &str = "name,iterations,real_time,cpu_time,time_unit,bytes_per_second,items_per_second,label,error_occurred,error_message";
+
+#[derive(Clone, Copy)]
+enum Scenario {
+    SequentialInsert,
+    RandomInsert,
+    LookupHit,
+    LookupMiss,
+    Delete,
+}
+
+impl Scenario {
+    const ALL: [Scenario; 5] = [
+        Self::SequentialInsert,
+        Self::RandomInsert,
+        Self::LookupHit,
+        Self::LookupMiss,
+        Self::Delete,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::SequentialInsert => "sequential_insert",
+            Self::RandomInsert => "random_insert",
+            Self::LookupHit => "lookup_hit",
+            Self::LookupMiss => "lookup_miss",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+struct Measurement {
+    name: String,
+    iterations: u64,
+    nanos_per_iteration: f64,
+}
+
+impl Measurement {
+    fn write_csv_row<W: Write>(&self, output: &mut W) -> io::Result<()> {
+        let nanos = self.nanos_per_iteration;
+        let items_per_second = if nanos > 0.0 {
+            format!("{:.3}", 1e9 / nanos)
+        } else {
+            String::new()
+        };
+
+        writeln!(
+            output,
+            "\"{}\",{},{:.3},{:.3},ns,,{},,,",
+            self.name, self.iterations, nanos, nanos, items_per_second
+        )
+    }
+}
+
+struct Workload {
+    sequential_keys: Vec<u64>,
+    random_keys: Vec<u64>,
+    missing_keys: Vec<u64>,
+    delete_order: Vec<u64>,
+}
+
+impl Workload {
+    fn new(size: usize) -> Self {
+        let mut random = XorShift64::new(0xB5_7EE5);
+        let sequential_keys = (0..size as u64).map(|key| key * 2).collect::<Vec<_>>();
+        let random_keys = shuffled(&sequential_keys, &mut random);
+        let missing_keys = random_keys.iter().map(|key| key + 1).collect();
+        let delete_order = shuffled(&sequential_keys, &mut random);
+
+        Self {
+            sequential_keys,
+            random_keys,
+            missing_keys,
+            delete_order,
+        }
+    }
+}
+
+fn shuffled(keys: &[u64], random: &mut XorShift64) -> Vec<u64> {
+    let mut keys = keys.to_vec();
+    for ndx in (1..keys.len()).rev() {
+        keys.swap(ndx, random.next_below(ndx as u64 + 1) as usize);
+    }
+
+    keys
+}
+
+fn build<T>(keys: &[u64]) -> T
+where
+    T: Default + Tree<u64, u64>,
+{
+    let mut tree = T::default();
+    for &key in keys {
+        tree.insert(key, key + 1);
+    }
+
+    tree
+}
+
+fn run_scenario<T>(scenario: Scenario, workload: &Workload) -> Duration
+where
+    T: Default + Tree<u64, u64>,
+{
+    match scenario {
+        Scenario::SequentialInsert | Scenario::RandomInsert => {
+            let keys = match scenario {
+                Scenario::SequentialInsert => &workload.sequential_keys,
+                _ => &workload.random_keys,
+            };
+
+            let mut tree = T::default();
+            let start = Instant::now();
+            for &key in keys {
+                tree.insert(key, key + 1);
+            }
+            let elapsed = start.elapsed();
+
+            black_box(tree);
+            elapsed
+        }
+        Scenario::LookupHit | Scenario::LookupMiss => {
+            let (keys, expected) = match scenario {
+                Scenario::LookupHit => (&workload.random_keys, Some(())),
+                _ => (&workload.missing_keys, None),
+            };
+
+            let tree = build::<T>(&workload.random_keys);
+            let start = Instant::now();
+            for &key in keys {
+                let found = black_box(tree.get(&key));
+                assert_eq!(found.map(|_| ()), expected, "Wrong lookup result");
+            }
+
+            start.elapsed()
+        }
+        Scenario::Delete => {
+            let mut tree = build::<T>(&workload.random_keys);
+            let start = Instant::now();
+            for key in &workload.delete_order {
+                black_box(tree.delete(key));
+            }
+            let elapsed = start.elapsed();
+
+            black_box(tree);
+            elapsed
+        }
+    }
+}
+
+fn measure<T>(
+    backend: &str,
+    scenario: Scenario,
+    workload: &Workload,
+    min_time: Duration,
+) -> Measurement
+where
+    T: Default + Tree<u64, u64>,
+{
+    let mut total_time = Duration::ZERO;
+    let mut repetitions = 0;
+
+    while total_time < min_time || repetitions == 0 {
+        total_time += run_scenario::<T>(scenario, workload);
+        repetitions += 1;
+    }
+
+    let operations = workload.sequential_keys.len().max(1) as u64;
+    let iterations = repetitions * operations;
+
+    Measurement {
+        name: format!(
+            "BM_{}_{}/{}",
+            backend,
+            scenario.name(),
+            workload.sequential_keys.len()
+        ),
+        iterations,
+        nanos_per_iteration: total_time.as_nanos() as f64 / iterations as f64,
+    }
+}
+
+fn measure_backend<T, W>(
+    backend: &str,
+    workload: &Workload,
+    min_time: Duration,
+    output: &mut W,
+) -> io::Result<()>
+where
+    T: Default + Tree<u64, u64>,
+    W: Write,
+{
+    for scenario in Scenario::ALL {
+        measure::<T>(backend, scenario, workload, min_time).write_csv_row(output)?;
+        output.flush()?;
+    }
+
+    Ok(())
+}
+
+pub fn run<W: Write>(size: usize, min_time: Duration, output: &mut W) -> io::Result<()> {
+    let workload = Workload::new(size);
+
+    writeln!(output, "{}", CSV_HEADER)?;
+    measure_backend::<ArrayBST<u64, u64>, _>("array_bst", &workload, min_time, output)?;
+    measure_backend::<ListBST<u64, u64>, _>("linked_bst", &workload, min_time, output)?;
+    measure_backend::<ArrayRedBlackTree<u64, u64>, _>("array_rb", &workload, min_time, output)?;
+    measure_backend::<ListRedBlackTree<u64, u64>, _>("linked_rb", &workload, min_time, output)?;
+    measure_backend::<BTreeMap<u64, u64>, _>("std_map", &workload, min_time, output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_output() {
+        let mut output = Vec::new();
+        run(16, Duration::ZERO, &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines.len(), 1 + 5 * Scenario::ALL.len());
+        assert!(lines[1].starts_with("\"BM_array_bst_sequential_insert/16\","));
+        assert!(lines[25].starts_with("\"BM_std_map_delete/16\","));
+
+        for line in &lines[1..] {
+            let columns = line.split(',').collect::<Vec<_>>();
+            assert_eq!(columns.len(), CSV_HEADER.split(',').count());
+            assert_eq!(columns[4], "ns");
+            assert_eq!(columns[1], "16");
+            assert!(columns[2].parse::<f64>().unwrap() >= 0.0);
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::ArrayRedBlackTree;
+use crate::b_plus_tree::BPlusTree;
+use crate::b_tree::BTree;
+use crate::random::XorShift64;
+use crate::traits::{OrderedTree, Tree};
+use std::collections::BTreeMap;
+use std::ops::Range;
+use test::{Bencher, black_box};
+
+const BENCH_SIZE: usize = 100_000;
+const SCAN_LENGTH: u64 = 10_000;
+
+trait RangeScan {
+    fn scan_sum(&self, range: Range<u64>) -> u64;
+}
+
+impl<T> RangeScan for T
+where
+    T: OrderedTree<u64, u64>,
+{
+    fn scan_sum(&self, range: Range<u64>) -> u64 {
+        self.range(range).map(|(_, val)| val).sum()
+    }
+}
+
+impl RangeScan for BTreeMap<u64, u64> {
+    fn scan_sum(&self, range: Range<u64>) -> u64 {
+        BTreeMap::range(self, range).map(|(_, val)| val).sum()
+    }
+}
+
+fn random_keys() -> Vec<u64> {
+    let mut random = XorShift64::new(0xBE4C);
+    (0..BENCH_SIZE)
+        .map(|_| random.next_below(BENCH_SIZE as u64 * 4))
+        .collect()
+}
+
+fn build<T>(keys: &[u64]) -> T
+where
+    T: Default + Tree<u64, u64>,
+{
+    let mut tree = T::default();
+    for &key in keys {
+        tree.insert(key, key);
+    }
+
+    tree
+}
+
+macro_rules! tree_benchmarks {
+    ($($name:ident: $tree:ty,)*) => {$(
+        mod $name {
+            use super::*;
+
+            #[bench]
+            fn random_insert(b: &mut Bencher) {
+                let keys = random_keys();
+
+                b.iter(|| black_box(build::<$tree>(&keys)));
+            }
+
+            #[bench]
+            fn lookup(b: &mut Bencher) {
+                let keys = random_keys();
+                let tree = build::<$tree>(&keys);
+
+                b.iter(|| black_box(keys.iter().filter_map(|key| tree.get(key)).count()));
+            }
+
+            #[bench]
+            fn range_scan(b: &mut Bencher) {
+                let keys = random_keys();
+                let tree = build::<$tree>(&keys);
+                let mut start = 0;
+
+                b.iter(|| {
+                    start = (start + SCAN_LENGTH) % (BENCH_SIZE as u64 * 4);
+                    black_box(tree.scan_sum(start..start + SCAN_LENGTH))
+                });
+            }
+        }
+    )*};
+}
+
+tree_benchmarks! {
+    array_red_black_tree: ArrayRedBlackTree<u64, u64>,
+    b_tree: BTree<u64, u64>,
+    b_plus_tree: BPlusTree<u64, u64>,
+    std_b_tree_map: BTreeMap<u64, u64>,
+}
@@ -0,0 +1,195 @@
+use crate::persistent_red_black_tree::PersistentRedBlackTree;
+use std::sync::{Mutex, PoisonError, RwLock};
+
+pub struct ConcurrentRedBlackTree<Key, Value>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    current: RwLock<PersistentRedBlackTree<Key, Value>>,
+    writer: Mutex<()>,
+}
+
+impl<Key, Value> Default for ConcurrentRedBlackTree<Key, Value>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            current: RwLock::new(PersistentRedBlackTree::new()),
+            writer: Mutex::new(()),
+        }
+    }
+}
+
+impl<Key, Value> ConcurrentRedBlackTree<Key, Value>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn snapshot(&self) -> PersistentRedBlackTree<Key, Value> {
+        // Versions are immutable, so a panicking writer cannot leave one half-built.
+        self.current
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.snapshot().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.snapshot().is_empty()
+    }
+
+    pub fn get(&self, key: &Key) -> Option<Value> {
+        self.snapshot().get(key).cloned()
+    }
+
+    pub fn contains_key(&self, key: &Key) -> bool {
+        self.snapshot().contains_key(key)
+    }
+
+    pub fn insert(&self, key: Key, value: Value) {
+        self.update(|version| version.insert(key, value));
+    }
+
+    pub fn delete(&self, key: &Key) -> Option<Value> {
+        let mut removed = None;
+        self.update(|version| {
+            removed = version.get(key).cloned();
+            version.delete(key)
+        });
+
+        removed
+    }
+
+    fn update<F>(&self, modifier: F)
+    where
+        F: FnOnce(&PersistentRedBlackTree<Key, Value>) -> PersistentRedBlackTree<Key, Value>,
+    {
+        let _writer = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+        let next = modifier(&self.snapshot());
+        *self.current.write().unwrap_or_else(PoisonError::into_inner) = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArrayRedBlackTree, ArraySplayTree};
+    use crate::traits::Tree;
+    use std::sync::Arc;
+    use std::thread;
+
+    const THREADS: u64 = 4;
+    const KEYS_PER_THREAD: u64 = 500;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_trees_are_thread_safe() {
+        assert_send_sync::<ArrayRedBlackTree<u64, u64>>();
+        assert_send_sync::<ArraySplayTree<u64, u64>>();
+        assert_send_sync::<PersistentRedBlackTree<u64, u64>>();
+        assert_send_sync::<ConcurrentRedBlackTree<u64, u64>>();
+    }
+
+    #[test]
+    fn test_shared_array_red_black_tree() {
+        let tree = Arc::new(RwLock::new(ArrayRedBlackTree::<u64, u64>::default()));
+
+        thread::scope(|scope| {
+            for thread_ndx in 0..THREADS {
+                let tree = Arc::clone(&tree);
+                scope.spawn(move || {
+                    for key in (0..KEYS_PER_THREAD).map(|i| i * THREADS + thread_ndx) {
+                        tree.write().unwrap().insert(key, key * 10);
+                        assert_eq!(tree.read().unwrap().get(&key), Some(key * 10));
+                    }
+                });
+            }
+        });
+
+        let tree = tree.read().unwrap();
+        assert!(tree.validate().is_ok());
+        for key in 0..THREADS * KEYS_PER_THREAD {
+            assert_eq!(tree.get(&key), Some(key * 10));
+        }
+    }
+
+    #[test]
+    fn test_shared_tree_visitors() {
+        let tree = RwLock::new(ArrayRedBlackTree::<u64, u64>::default());
+        for key in 0..KEYS_PER_THREAD {
+            tree.write().unwrap().insert(key, 0);
+        }
+
+        thread::scope(|scope| {
+            for _ in 0..THREADS {
+                let tree = &tree;
+                scope.spawn(move || {
+                    tree.write().unwrap().in_order_for_each_mut(|key, val| *val += key);
+
+                    let tree = tree.read().unwrap();
+                    assert!(tree.in_order().all(|(key, val)| *val % key.max(&1) == 0));
+                });
+            }
+        });
+
+        let tree = tree.into_inner().unwrap();
+        assert!(tree.validate().is_ok());
+        assert!(tree.in_order().all(|(key, val)| *val == key * THREADS));
+    }
+
+    #[test]
+    fn test_readers_see_consistent_snapshots() {
+        let tree = ConcurrentRedBlackTree::<u64, u64>::new();
+
+        thread::scope(|scope| {
+            for thread_ndx in 0..THREADS {
+                let tree = &tree;
+                scope.spawn(move || {
+                    for key in (0..KEYS_PER_THREAD).map(|i| i * THREADS + thread_ndx) {
+                        tree.insert(key, key);
+                    }
+                    for key in (0..KEYS_PER_THREAD).map(|i| i * THREADS + thread_ndx) {
+                        if key % 3 == 0 {
+                            assert_eq!(tree.delete(&key), Some(key));
+                        }
+                    }
+                });
+            }
+
+            for _ in 0..THREADS {
+                let tree = &tree;
+                scope.spawn(move || {
+                    for _ in 0..100 {
+                        let snapshot = tree.snapshot();
+                        assert!(snapshot.validate().is_ok());
+                        assert_eq!(snapshot.iter().count(), snapshot.len());
+                        assert!(snapshot.iter().all(|(key, value)| key == value));
+                    }
+                });
+            }
+        });
+
+        let snapshot = tree.snapshot();
+        assert!(snapshot.validate().is_ok());
+        assert_eq!(
+            snapshot.iter().map(|(key, _)| *key).collect::<Vec<_>>(),
+            (0..THREADS * KEYS_PER_THREAD)
+                .filter(|key| key % 3 != 0)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(tree.delete(&0), None);
+        assert_eq!(tree.get(&1), Some(1));
+    }
+}
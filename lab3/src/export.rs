@@ -0,0 +1,652 @@
+use crate::traits::{NodeIdentifiableTree, Tree};
+use std::fmt::Write;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LoadError {
+    UnexpectedEnd,
+    UnexpectedCharacter(usize, char),
+    MissingField(String),
+    InvalidField(String, String),
+    KeysOutOfOrder(String),
+    InvalidColors(String),
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of input"),
+            Self::UnexpectedCharacter(position, character) => {
+                write!(
+                    f,
+                    "unexpected character {:?} at position {}",
+                    character, position
+                )
+            }
+            Self::MissingField(field) => write!(f, "node has no field {:?}", field),
+            Self::InvalidField(field, text) => write!(f, "cannot parse {:?} as {}", text, field),
+            Self::KeysOutOfOrder(key) => write!(f, "key {} breaks the search tree order", key),
+            Self::InvalidColors(key) => {
+                write!(f, "colors around key {} break the red-black invariants", key)
+            }
+        }
+    }
+}
+
+enum Json {
+    Null,
+    Scalar(String),
+    Object(Vec<(String, Json)>),
+}
+
+impl Drop for Json {
+    fn drop(&mut self) {
+        let Json::Object(fields) = self else {
+            return;
+        };
+
+        let mut pending = std::mem::take(fields);
+        while let Some((_, mut json)) = pending.pop() {
+            if let Json::Object(fields) = &mut json {
+                pending.append(fields);
+            }
+        }
+    }
+}
+
+pub(crate) struct Fields {
+    fields: Vec<(String, Json)>,
+}
+
+impl Fields {
+    fn get(&self, name: &str) -> Option<&Json> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, json)| json)
+    }
+
+    pub(crate) fn get_str(&self, name: &str) -> Result<&str, LoadError> {
+        match self.get(name) {
+            Some(Json::Scalar(text)) => Ok(text),
+            _ => Err(LoadError::MissingField(name.to_string())),
+        }
+    }
+
+    pub(crate) fn parse<T: FromStr>(&self, name: &str) -> Result<T, LoadError> {
+        let text = self.get_str(name)?;
+        text.parse()
+            .map_err(|_| LoadError::InvalidField(name.to_string(), text.to_string()))
+    }
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            chars: text.char_indices().peekable(),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+    }
+
+    fn peek(&mut self) -> Result<(usize, char), LoadError> {
+        self.skip_whitespace();
+        self.chars.peek().copied().ok_or(LoadError::UnexpectedEnd)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), LoadError> {
+        match self.peek()? {
+            (_, c) if c == expected => {
+                self.chars.next();
+                Ok(())
+            }
+            (position, c) => Err(LoadError::UnexpectedCharacter(position, c)),
+        }
+    }
+
+    fn parse_document(&mut self) -> Result<Json, LoadError> {
+        let json = self.parse_value()?;
+        self.skip_whitespace();
+
+        match self.chars.next() {
+            Some((position, c)) => Err(LoadError::UnexpectedCharacter(position, c)),
+            None => Ok(json),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Json, LoadError> {
+        let mut open_objects: Vec<(Vec<(String, Json)>, String)> = Vec::new();
+
+        loop {
+            let mut json = match self.peek()? {
+                (_, '{') => {
+                    self.chars.next();
+                    if self.peek()?.1 != '}' {
+                        open_objects.push((Vec::new(), self.parse_field_name()?));
+                        continue;
+                    }
+
+                    self.chars.next();
+                    Json::Object(Vec::new())
+                }
+                _ => self.parse_scalar()?,
+            };
+
+            loop {
+                let Some((fields, name)) = open_objects.last_mut() else {
+                    return Ok(json);
+                };
+                fields.push((std::mem::take(name), json));
+
+                match self.peek()? {
+                    (_, ',') => {
+                        self.chars.next();
+                        *name = self.parse_field_name()?;
+                        break;
+                    }
+                    (_, '}') => {
+                        self.chars.next();
+                        let (fields, _) = open_objects.pop().expect("Object not found");
+                        json = Json::Object(fields);
+                    }
+                    (position, c) => return Err(LoadError::UnexpectedCharacter(position, c)),
+                }
+            }
+        }
+    }
+
+    fn parse_scalar(&mut self) -> Result<Json, LoadError> {
+        match self.peek()? {
+            (_, '"') => self.parse_string().map(Json::Scalar),
+            (_, c) if c == '-' || c.is_ascii_alphanumeric() => {
+                let mut word = String::new();
+                while let Some((_, c)) = self
+                    .chars
+                    .next_if(|&(_, c)| c.is_ascii_alphanumeric() || "+-.".contains(c))
+                {
+                    word.push(c);
+                }
+
+                Ok(if word == "null" {
+                    Json::Null
+                } else {
+                    Json::Scalar(word)
+                })
+            }
+            (position, c) => Err(LoadError::UnexpectedCharacter(position, c)),
+        }
+    }
+
+    fn parse_field_name(&mut self) -> Result<String, LoadError> {
+        let name = self.parse_string()?;
+        self.expect(':')?;
+        Ok(name)
+    }
+
+    fn parse_string(&mut self) -> Result<String, LoadError> {
+        let mut result = String::new();
+        self.expect('"')?;
+
+        loop {
+            match self.chars.next().ok_or(LoadError::UnexpectedEnd)? {
+                (_, '"') => return Ok(result),
+                (_, '\\') => match self.chars.next().ok_or(LoadError::UnexpectedEnd)? {
+                    (_, 'n') => result.push('\n'),
+                    (_, 't') => result.push('\t'),
+                    (_, 'r') => result.push('\r'),
+                    (_, 'b') => result.push('\u{8}'),
+                    (_, 'f') => result.push('\u{c}'),
+                    (position, 'u') => {
+                        let mut code = 0;
+                        for _ in 0..4 {
+                            let (position, c) = self.chars.next().ok_or(LoadError::UnexpectedEnd)?;
+                            let digit = c
+                                .to_digit(16)
+                                .ok_or(LoadError::UnexpectedCharacter(position, c))?;
+                            code = code * 16 + digit;
+                        }
+                        let c = char::from_u32(code)
+                            .ok_or(LoadError::UnexpectedCharacter(position, 'u'))?;
+                        result.push(c);
+                    }
+                    (_, c) => result.push(c),
+                },
+                (_, c) => result.push(c),
+            }
+        }
+    }
+}
+
+fn escape_json(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 2);
+    result.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '\r' => result.push_str("\\r"),
+            c if c.is_control() => {
+                let _ = write!(result, "\\u{:04x}", c as u32);
+            }
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+pub(crate) fn to_dot<Key, Value, NodeId, T, F>(tree: &T, describe: F) -> String
+where
+    Key: std::fmt::Display,
+    NodeId: Clone,
+    T: NodeIdentifiableTree<Key, Value, NodeId>,
+    F: Fn(&Value) -> (String, &'static str),
+{
+    let mut result = String::from("digraph Tree {\n    node [shape=box];\n");
+    let mut nil_count = 0;
+    let mut stack = Vec::new();
+
+    match tree.get_root_id() {
+        Some(root_id) => stack.push((root_id, 0)),
+        None => result.push_str("    nil0 [shape=point];\n"),
+    }
+
+    let mut node_count = 1;
+    while let Some((node_id, node_ndx)) = stack.pop() {
+        let key = tree.get_key_by_id(node_id.clone()).expect("Node not found");
+        let value = tree.get_by_id(node_id.clone()).expect("Node not found");
+        let (value, attributes) = describe(&value);
+        let _ = writeln!(
+            result,
+            "    n{} [label=\"{}\\n{}\"{}];",
+            node_ndx,
+            escape_dot(&key.to_string()),
+            escape_dot(&value),
+            attributes
+        );
+
+        let sons = [
+            ("left", tree.get_left_son_id(node_id.clone())),
+            ("right", tree.get_right_son_id(node_id)),
+        ];
+
+        let mut pending = Vec::new();
+        for (side, son_id) in sons {
+            if let Some(son_id) = son_id {
+                let _ = writeln!(
+                    result,
+                    "    n{} -> n{} [label=\"{}\"];",
+                    node_ndx, node_count, side
+                );
+                pending.push((son_id, node_count));
+                node_count += 1;
+            } else {
+                let _ = writeln!(result, "    nil{} [shape=point];", nil_count);
+                let _ = writeln!(
+                    result,
+                    "    n{} -> nil{} [label=\"{}\"];",
+                    node_ndx, nil_count, side
+                );
+                nil_count += 1;
+            }
+        }
+
+        stack.extend(pending.into_iter().rev());
+    }
+
+    result.push_str("}\n");
+    result
+}
+
+enum JsonStep<NodeId> {
+    Subtree(Option<NodeId>, usize),
+    Text(String),
+}
+
+pub(crate) fn to_json<Key, Value, NodeId, T, F>(tree: &T, describe: F) -> String
+where
+    Key: std::fmt::Display,
+    NodeId: Clone,
+    T: NodeIdentifiableTree<Key, Value, NodeId>,
+    F: Fn(&Value) -> Vec<(&'static str, String)>,
+{
+    let mut result = String::new();
+    let mut stack = vec![JsonStep::Subtree(tree.get_root_id(), 0)];
+
+    while let Some(step) = stack.pop() {
+        let (node_id, indent) = match step {
+            JsonStep::Text(text) => {
+                result.push_str(&text);
+                continue;
+            }
+            JsonStep::Subtree(None, _) => {
+                result.push_str("null");
+                continue;
+            }
+            JsonStep::Subtree(Some(node_id), indent) => (node_id, indent),
+        };
+
+        let key = tree.get_key_by_id(node_id.clone()).expect("Node not found");
+        let fields = describe(&tree.get_by_id(node_id.clone()).expect("Node not found"));
+        let padding = "  ".repeat(indent + 1);
+
+        let _ = write!(
+            result,
+            "{{\n{}\"key\": {}",
+            padding,
+            escape_json(&key.to_string())
+        );
+        for (name, text) in fields {
+            let _ = write!(result, ",\n{}\"{}\": {}", padding, name, escape_json(&text));
+        }
+        let _ = write!(result, ",\n{}\"left\": ", padding);
+
+        stack.push(JsonStep::Text(format!("\n{}}}", "  ".repeat(indent))));
+        stack.push(JsonStep::Subtree(tree.get_right_son_id(node_id.clone()), indent + 1));
+        stack.push(JsonStep::Text(format!(",\n{}\"right\": ", padding)));
+        stack.push(JsonStep::Subtree(tree.get_left_son_id(node_id), indent + 1));
+    }
+
+    result.push('\n');
+    result
+}
+
+pub(crate) fn from_json<Key, Value, T, F>(json: &str, make_value: F) -> Result<T, LoadError>
+where
+    Key: Ord + FromStr + std::fmt::Display,
+    T: Default + Tree<Key, Value>,
+    F: Fn(&Fields) -> Result<Value, LoadError>,
+{
+    let mut tree = T::default();
+    let mut bounds = Vec::new();
+    let mut stack = vec![(Parser::new(json).parse_document()?, None, None)];
+
+    while let Some((mut json, lower, upper)) = stack.pop() {
+        let mut fields = match &mut json {
+            Json::Null => continue,
+            Json::Scalar(text) => {
+                return Err(LoadError::InvalidField("node".to_string(), std::mem::take(text)));
+            }
+            Json::Object(fields) => Fields {
+                fields: std::mem::take(fields),
+            },
+        };
+
+        let key = fields.parse::<Key>("key")?;
+        if lower.is_some_and(|lower: usize| key <= bounds[lower])
+            || upper.is_some_and(|upper: usize| key >= bounds[upper])
+        {
+            return Err(LoadError::KeysOutOfOrder(key.to_string()));
+        }
+
+        let mut take_son = |name: &str| {
+            let position = fields.fields.iter().position(|(field, _)| field == name);
+            position.map_or(Json::Null, |position| fields.fields.swap_remove(position).1)
+        };
+        let (left, right) = (take_son("left"), take_son("right"));
+
+        tree.insert(fields.parse("key")?, make_value(&fields)?);
+        bounds.push(key);
+        stack.push((right, Some(bounds.len() - 1), upper));
+        stack.push((left, lower, Some(bounds.len() - 1)));
+    }
+
+    Ok(tree)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LoadError;
+    use crate::array_bst::ArrayBST;
+    use crate::list_bst::ListBST;
+    use crate::test_utils::XorShift64;
+    use crate::traits::Tree;
+    use crate::{ArrayRedBlackTree, ListRedBlackTree};
+
+    const RED_BLACK_JSON: &str = r#"{
+  "key": "2",
+  "value": "20",
+  "color": "black",
+  "left": {
+    "key": "1",
+    "value": "10",
+    "color": "black",
+    "left": null,
+    "right": null
+  },
+  "right": {
+    "key": "3",
+    "value": "30",
+    "color": "black",
+    "left": null,
+    "right": {
+      "key": "4",
+      "value": "40",
+      "color": "red",
+      "left": null,
+      "right": null
+    }
+  }
+}
+"#;
+
+    const RED_BLACK_DOT: &str = r#"digraph Tree {
+    node [shape=box];
+    n0 [label="2\n20", style=filled, fillcolor=black, fontcolor=white];
+    n0 -> n1 [label="left"];
+    n0 -> n2 [label="right"];
+    n1 [label="1\n10", style=filled, fillcolor=black, fontcolor=white];
+    nil0 [shape=point];
+    n1 -> nil0 [label="left"];
+    nil1 [shape=point];
+    n1 -> nil1 [label="right"];
+    n2 [label="3\n30", style=filled, fillcolor=black, fontcolor=white];
+    nil2 [shape=point];
+    n2 -> nil2 [label="left"];
+    n2 -> n3 [label="right"];
+    n3 [label="4\n40", style=filled, fillcolor=red, fontcolor=white];
+    nil3 [shape=point];
+    n3 -> nil3 [label="left"];
+    nil4 [shape=point];
+    n3 -> nil4 [label="right"];
+}
+"#;
+
+    #[test]
+    fn test_red_black_golden() {
+        let mut tree = ArrayRedBlackTree::<i32, i32>::new();
+        for key in 1..=4 {
+            tree.insert(key, key * 10);
+        }
+
+        assert_eq!(tree.to_json(), RED_BLACK_JSON);
+        assert_eq!(tree.to_dot(), RED_BLACK_DOT);
+
+        let loaded = ListRedBlackTree::<i32, i32>::from_json(RED_BLACK_JSON).unwrap();
+        assert_eq!(loaded.validate(), Ok(2));
+        assert_eq!(loaded.to_json(), RED_BLACK_JSON);
+        assert_eq!(loaded.to_dot(), RED_BLACK_DOT);
+    }
+
+    #[test]
+    fn test_plain_bst() {
+        let mut tree = ArrayBST::<i32, String>::new();
+        assert_eq!(tree.to_json(), "null\n");
+        assert_eq!(
+            tree.to_dot(),
+            "digraph Tree {\n    node [shape=box];\n    nil0 [shape=point];\n}\n"
+        );
+
+        tree.insert(1, "one".to_string());
+        tree.insert(2, "\"two\"".to_string());
+        assert_eq!(
+            tree.to_json(),
+            "{\n  \"key\": \"1\",\n  \"value\": \"one\",\n  \"left\": null,\n  \"right\": {\n    \"key\": \"2\",\n    \"value\": \"\\\"two\\\"\",\n    \"left\": null,\n    \"right\": null\n  }\n}\n"
+        );
+        assert!(tree.to_dot().contains("n1 [label=\"2\\n\\\"two\\\"\"];"));
+
+        let loaded = ListBST::<i32, String>::from_json(&tree.to_json()).unwrap();
+        assert_eq!(loaded.get(&2), Some("\"two\"".to_string()));
+        assert_eq!(loaded.to_json(), tree.to_json());
+        assert_eq!(loaded.to_dot(), tree.to_dot());
+    }
+
+    #[test]
+    fn test_exact_shape_round_trip() {
+        let mut random = XorShift64::new(44);
+        let mut bst = ListBST::<u64, u64>::new();
+        let mut red_black_tree = ListRedBlackTree::<u64, u64>::new();
+
+        for _ in 0..200 {
+            let key = random.next_below(1000);
+            bst.insert(key, key * 3);
+            red_black_tree.insert(key, key * 3);
+        }
+
+        let json = bst.to_json();
+        let loaded = ArrayBST::<u64, u64>::from_json(&json).unwrap();
+        assert_eq!(loaded.to_json(), json);
+        assert_eq!(loaded.to_dot(), bst.to_dot());
+
+        let json = red_black_tree.to_json();
+        let loaded = ArrayRedBlackTree::<u64, u64>::from_json(&json).unwrap();
+        assert_eq!(loaded.validate().ok(), red_black_tree.validate().ok());
+        assert_eq!(loaded.to_json(), json);
+        assert_eq!(loaded.to_dot(), red_black_tree.to_dot());
+    }
+
+    #[test]
+    fn test_load_errors() {
+        let load = ArrayRedBlackTree::<i32, i32>::from_json;
+
+        let tree = load(r#"{"key": 5, "value": -7, "color": "black", "left": null}"#).unwrap();
+        assert_eq!(tree.get(&5), Some(-7));
+
+        assert_eq!(load("").err(), Some(LoadError::UnexpectedEnd));
+        assert_eq!(
+            load("null x").err(),
+            Some(LoadError::UnexpectedCharacter(5, 'x'))
+        );
+        let tree = load(r#"{"key": "\u0031", "value": 2, "color": "black", "note": "\u00e9"}"#);
+        assert_eq!(tree.unwrap().get(&1), Some(2));
+        assert_eq!(
+            load(r#"{"key": "\u00x1", "value": 2}"#).err(),
+            Some(LoadError::UnexpectedCharacter(13, 'x'))
+        );
+        assert_eq!(load(r#"{"key": "\u00"#).err(), Some(LoadError::UnexpectedEnd));
+        assert_eq!(
+            load("[]").err(),
+            Some(LoadError::UnexpectedCharacter(0, '['))
+        );
+        assert_eq!(
+            load(r#"{"key": 1, "color": "red"}"#).err(),
+            Some(LoadError::MissingField("value".to_string()))
+        );
+        assert_eq!(
+            load(r#"{"key": 1, "value": 1, "color": "green"}"#).err(),
+            Some(LoadError::InvalidField(
+                "color".to_string(),
+                "green".to_string()
+            ))
+        );
+        assert_eq!(
+            load(r#"{"key": "one", "value": 1, "color": "red"}"#).err(),
+            Some(LoadError::InvalidField(
+                "key".to_string(),
+                "one".to_string()
+            ))
+        );
+        assert_eq!(
+            load(r#"{"key": 2, "value": 0, "color": "black", "left": {"key": 3, "value": 0, "color": "red"}}"#).err(),
+            Some(LoadError::KeysOutOfOrder("3".to_string()))
+        );
+        assert_eq!(
+            ArrayBST::<i32, i32>::from_json(
+                r#"{"key": 2, "value": 0, "right": {"key": 2, "value": 1}}"#
+            )
+            .err(),
+            Some(LoadError::KeysOutOfOrder("2".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_deep_trees() {
+        let on_small_stack = |check: fn() -> bool| {
+            std::thread::Builder::new()
+                .stack_size(128 << 10)
+                .spawn(check)
+                .unwrap()
+                .join()
+                .unwrap()
+        };
+
+        assert!(on_small_stack(|| {
+            let mut tree = ArrayBST::<i32, i32>::new();
+            for key in 0..1500 {
+                tree.insert(key, -key);
+            }
+
+            let json = tree.to_json();
+            ArrayBST::<i32, i32>::from_json(&json).unwrap().to_json() == json
+        }));
+
+        assert!(on_small_stack(|| {
+            let depth = 100_000;
+            let nested = format!("{}null{}", "{\"left\": ".repeat(depth), "}".repeat(depth));
+            let unterminated = "{\"left\": ".repeat(depth);
+
+            ArrayBST::<i32, i32>::from_json(&nested).err()
+                == Some(LoadError::MissingField("key".to_string()))
+                && ArrayBST::<i32, i32>::from_json(&unterminated).err()
+                    == Some(LoadError::UnexpectedEnd)
+        }));
+    }
+
+    #[test]
+    fn test_invalid_colors() {
+        let load = ListRedBlackTree::<i32, i32>::from_json;
+
+        assert_eq!(
+            load(r#"{"key": 1, "value": 1, "color": "red"}"#).err(),
+            Some(LoadError::InvalidColors("1".to_string()))
+        );
+        assert_eq!(
+            load(
+                r#"{"key": 2, "value": 0, "color": "black",
+                    "left": {"key": 1, "value": 0, "color": "red",
+                             "left": {"key": 0, "value": 0, "color": "red"}}}"#
+            )
+            .err(),
+            Some(LoadError::InvalidColors("0".to_string()))
+        );
+        assert_eq!(
+            load(
+                r#"{"key": 2, "value": 0, "color": "black",
+                    "left": {"key": 1, "value": 0, "color": "black"}}"#
+            )
+            .err(),
+            Some(LoadError::InvalidColors("2".to_string()))
+        );
+        assert!(
+            load(
+                r#"{"key": 2, "value": 0, "color": "black",
+                    "left": {"key": 1, "value": 0, "color": "red"}}"#
+            )
+            .is_ok()
+        );
+    }
+}
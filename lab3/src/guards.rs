@@ -0,0 +1,70 @@
+use std::cell;
+use std::ops::{Deref, DerefMut};
+
+pub type GuardRef<'a, G, T> = <G as Guards>::Ref<'a, T>;
+pub type GuardRefMut<'a, G, T> = <G as Guards>::RefMut<'a, T>;
+
+pub trait Guards {
+    type Ref<'a, T: 'a>: Deref<Target = T>;
+    type RefMut<'a, T: 'a>: DerefMut<Target = T>;
+
+    fn map<'a, T: 'a, U: 'a, F>(guard: Self::Ref<'a, T>, projection: F) -> Self::Ref<'a, U>
+    where
+        F: FnOnce(&T) -> &U;
+
+    fn map_mut<'a, T: 'a, U: 'a, F>(
+        guard: Self::RefMut<'a, T>,
+        projection: F,
+    ) -> Self::RefMut<'a, U>
+    where
+        F: FnOnce(&mut T) -> &mut U;
+}
+
+pub struct PlainGuards;
+
+impl Guards for PlainGuards {
+    type Ref<'a, T: 'a> = &'a T;
+    type RefMut<'a, T: 'a> = &'a mut T;
+
+    #[inline(always)]
+    fn map<'a, T: 'a, U: 'a, F>(guard: &'a T, projection: F) -> &'a U
+    where
+        F: FnOnce(&T) -> &U,
+    {
+        projection(guard)
+    }
+
+    #[inline(always)]
+    fn map_mut<'a, T: 'a, U: 'a, F>(guard: &'a mut T, projection: F) -> &'a mut U
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        projection(guard)
+    }
+}
+
+pub struct CellGuards;
+
+impl Guards for CellGuards {
+    type Ref<'a, T: 'a> = cell::Ref<'a, T>;
+    type RefMut<'a, T: 'a> = cell::RefMut<'a, T>;
+
+    #[inline(always)]
+    fn map<'a, T: 'a, U: 'a, F>(guard: cell::Ref<'a, T>, projection: F) -> cell::Ref<'a, U>
+    where
+        F: FnOnce(&T) -> &U,
+    {
+        cell::Ref::map(guard, projection)
+    }
+
+    #[inline(always)]
+    fn map_mut<'a, T: 'a, U: 'a, F>(
+        guard: cell::RefMut<'a, T>,
+        projection: F,
+    ) -> cell::RefMut<'a, U>
+    where
+        F: FnOnce(&mut T) -> &mut U,
+    {
+        cell::RefMut::map(guard, projection)
+    }
+}
@@ -0,0 +1,781 @@
+use crate::guards::{GuardRef, GuardRefMut, Guards};
+use crate::observer::Event;
+use crate::red_black_tree::{self, Data, RedBlackTree};
+use crate::traits::{NodeIdentifiableTree, ObservableTree, ParentifiedTree, RotatableTree, Tree};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<Point> {
+    start: Point,
+    end: Point,
+}
+
+impl<Point> Interval<Point>
+where
+    Point: Ord,
+{
+    pub fn new(start: Point, end: Point) -> Self {
+        assert!(start <= end, "Interval start must not exceed its end");
+        Self { start, end }
+    }
+
+    pub fn start(&self) -> &Point {
+        &self.start
+    }
+
+    pub fn end(&self) -> &Point {
+        &self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.start <= *point && *point <= self.end
+    }
+}
+
+impl<Point> std::fmt::Display for Interval<Point>
+where
+    Point: std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.start, self.end)
+    }
+}
+
+#[derive(Clone)]
+pub struct Augmented<Point, Value> {
+    value: Value,
+    max_end: Point,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Violation<NodeId> {
+    RedBlack(red_black_tree::Violation<NodeId>),
+    WrongMaxEnd(NodeId),
+}
+
+impl<NodeId> std::fmt::Display for Violation<NodeId>
+where
+    NodeId: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::RedBlack(violation) => write!(f, "{}", violation),
+            Self::WrongMaxEnd(node_id) => {
+                write!(f, "node {:?} stores a wrong maximal endpoint", node_id)
+            }
+        }
+    }
+}
+
+pub struct AugmentedBST<Point, Value, ImplTree, NodeId>
+where
+    Point: Ord + Clone,
+    Value: Clone,
+    NodeId: Clone + PartialEq,
+    ImplTree: Default
+        + Tree<Interval<Point>, Augmented<Point, Value>>
+        + NodeIdentifiableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ParentifiedTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + RotatableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ObservableTree<Interval<Point>, NodeId>,
+{
+    bst: ImplTree,
+    _phantom_point: PhantomData<Point>,
+    _phantom_value: PhantomData<Value>,
+    _phantom_node_id: PhantomData<NodeId>,
+}
+
+impl<Point, Value, ImplTree, NodeId> Default for AugmentedBST<Point, Value, ImplTree, NodeId>
+where
+    Point: Ord + Clone,
+    Value: Clone,
+    NodeId: Clone + PartialEq,
+    ImplTree: Default
+        + Tree<Interval<Point>, Augmented<Point, Value>>
+        + NodeIdentifiableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ParentifiedTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + RotatableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ObservableTree<Interval<Point>, NodeId>,
+{
+    fn default() -> Self {
+        Self {
+            bst: Default::default(),
+            _phantom_point: Default::default(),
+            _phantom_value: Default::default(),
+            _phantom_node_id: Default::default(),
+        }
+    }
+}
+
+impl<Point, Value, ImplTree, NodeId> AugmentedBST<Point, Value, ImplTree, NodeId>
+where
+    Point: Ord + Clone,
+    Value: Clone,
+    NodeId: Clone + PartialEq,
+    ImplTree: Default
+        + Tree<Interval<Point>, Augmented<Point, Value>>
+        + NodeIdentifiableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ParentifiedTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + RotatableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ObservableTree<Interval<Point>, NodeId>,
+{
+    pub fn get_max_end(&self, node_id: NodeId) -> Option<GuardRef<'_, ImplTree::Guards, Point>> {
+        self.bst
+            .get_by_id(node_id)
+            .map(|data| <ImplTree::Guards as Guards>::map(data, |data| &data.max_end))
+    }
+
+    fn compute_max_end(&self, node_id: NodeId) -> Point {
+        let mut max_end = self
+            .bst
+            .get_key_by_id(node_id.clone())
+            .expect("Node not found")
+            .end
+            .clone();
+
+        for son_id in [
+            self.bst.get_left_son_id(node_id.clone()),
+            self.bst.get_right_son_id(node_id),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            let son_max_end = self.get_max_end(son_id).expect("Son not found");
+            if *son_max_end > max_end {
+                max_end = son_max_end.clone();
+            }
+        }
+
+        max_end
+    }
+
+    fn update_max_end(&mut self, node_id: NodeId) {
+        let max_end = self.compute_max_end(node_id.clone());
+        self.bst
+            .modify(node_id, |data| data.max_end = max_end.clone());
+    }
+
+    fn update_max_ends_upwards(&mut self, mut maybe_node_id: Option<NodeId>) {
+        while let Some(node_id) = maybe_node_id {
+            self.update_max_end(node_id.clone());
+            maybe_node_id = self.bst.get_parent_id(node_id);
+        }
+    }
+
+    fn get_minimum_id(&self, mut node_id: NodeId) -> NodeId {
+        while let Some(left_son_id) = self.bst.get_left_son_id(node_id.clone()) {
+            node_id = left_son_id;
+        }
+
+        node_id
+    }
+}
+
+impl<Point, Value, ImplTree, NodeId> Tree<Interval<Point>, Value>
+    for AugmentedBST<Point, Value, ImplTree, NodeId>
+where
+    Point: Ord + Clone,
+    Value: Clone,
+    NodeId: Clone + PartialEq,
+    ImplTree: Default
+        + Tree<Interval<Point>, Augmented<Point, Value>>
+        + NodeIdentifiableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ParentifiedTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + RotatableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ObservableTree<Interval<Point>, NodeId>,
+{
+    fn insert(&mut self, key: Interval<Point>, val: Value) {
+        let _ = self.insert_and_get_id(key, val);
+    }
+
+    fn get(&self, key: &Interval<Point>) -> Option<Value> {
+        self.bst.get(key).map(|data| data.value)
+    }
+
+    fn delete(&mut self, key: &Interval<Point>) -> Option<Value> {
+        let node_id = self.bst.get_id(key)?;
+        self.delete_by_id(node_id)
+    }
+}
+
+impl<Point, Value, ImplTree, NodeId> NodeIdentifiableTree<Interval<Point>, Value, NodeId>
+    for AugmentedBST<Point, Value, ImplTree, NodeId>
+where
+    Point: Ord + Clone,
+    Value: Clone,
+    NodeId: Clone + PartialEq,
+    ImplTree: Default
+        + Tree<Interval<Point>, Augmented<Point, Value>>
+        + NodeIdentifiableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ParentifiedTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + RotatableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ObservableTree<Interval<Point>, NodeId>,
+{
+    type Guards = ImplTree::Guards;
+
+    fn insert_and_get_id(&mut self, key: Interval<Point>, val: Value) -> NodeId {
+        let max_end = key.end.clone();
+        let node_id = self.bst.insert_and_get_id(
+            key,
+            Augmented {
+                value: val,
+                max_end,
+            },
+        );
+
+        self.update_max_ends_upwards(Some(node_id.clone()));
+        node_id
+    }
+
+    fn get_id(&self, key: &Interval<Point>) -> Option<NodeId> {
+        self.bst.get_id(key)
+    }
+
+    fn delete_by_id(&mut self, node_id: NodeId) -> Option<Value> {
+        let lowest_changed_id = match (
+            self.bst.get_left_son_id(node_id.clone()),
+            self.bst.get_right_son_id(node_id.clone()),
+        ) {
+            (Some(_), Some(right_son_id)) => {
+                let successor_id = self.get_minimum_id(right_son_id.clone());
+                if successor_id == right_son_id {
+                    Some(successor_id)
+                } else {
+                    self.bst.get_parent_id(successor_id)
+                }
+            }
+            _ => self.bst.get_parent_id(node_id.clone()),
+        };
+
+        let removed = self.bst.delete_by_id(node_id)?;
+        self.update_max_ends_upwards(lowest_changed_id);
+
+        Some(removed.value)
+    }
+
+    fn get_key_by_id(&self, node_id: NodeId) -> Option<&Interval<Point>> {
+        self.bst.get_key_by_id(node_id)
+    }
+
+    fn get_subtree_size(&self, node_id: NodeId) -> usize {
+        self.bst.get_subtree_size(node_id)
+    }
+
+    fn get_by_id(&self, node_id: NodeId) -> Option<GuardRef<'_, ImplTree::Guards, Value>> {
+        self.bst
+            .get_by_id(node_id)
+            .map(|data| <ImplTree::Guards as Guards>::map(data, |data| &data.value))
+    }
+
+    fn get_by_id_mut(
+        &mut self,
+        node_id: NodeId,
+    ) -> Option<GuardRefMut<'_, ImplTree::Guards, Value>> {
+        self.bst
+            .get_by_id_mut(node_id)
+            .map(|data| <ImplTree::Guards as Guards>::map_mut(data, |data| &mut data.value))
+    }
+
+    fn get_entry_by_id_mut(
+        &mut self,
+        node_id: NodeId,
+    ) -> Option<(&Interval<Point>, GuardRefMut<'_, ImplTree::Guards, Value>)> {
+        self.bst.get_entry_by_id_mut(node_id).map(|(key, data)| {
+            (key, <ImplTree::Guards as Guards>::map_mut(data, |data| &mut data.value))
+        })
+    }
+
+    fn get_left_son_id(&self, node_id: NodeId) -> Option<NodeId> {
+        self.bst.get_left_son_id(node_id)
+    }
+
+    fn get_right_son_id(&self, node_id: NodeId) -> Option<NodeId> {
+        self.bst.get_right_son_id(node_id)
+    }
+
+    fn get_root_id(&self) -> Option<NodeId> {
+        self.bst.get_root_id()
+    }
+
+    fn get_left_son_mut(
+        &mut self,
+        node_id: NodeId,
+    ) -> Option<GuardRefMut<'_, ImplTree::Guards, Value>> {
+        self.bst
+            .get_left_son_mut(node_id)
+            .map(|data| <ImplTree::Guards as Guards>::map_mut(data, |data| &mut data.value))
+    }
+
+    fn get_right_son_mut(
+        &mut self,
+        node_id: NodeId,
+    ) -> Option<GuardRefMut<'_, ImplTree::Guards, Value>> {
+        self.bst
+            .get_right_son_mut(node_id)
+            .map(|data| <ImplTree::Guards as Guards>::map_mut(data, |data| &mut data.value))
+    }
+
+    fn get_root_mut(&mut self) -> Option<GuardRefMut<'_, ImplTree::Guards, Value>> {
+        self.bst
+            .get_root_mut()
+            .map(|data| <ImplTree::Guards as Guards>::map_mut(data, |data| &mut data.value))
+    }
+
+    fn modify<F>(&mut self, node_id: NodeId, mut modifier: F)
+    where
+        F: FnMut(&mut Value),
+    {
+        self.bst.modify(node_id, |data| modifier(&mut data.value));
+    }
+}
+
+impl<Point, Value, ImplTree, NodeId> ParentifiedTree<Interval<Point>, Value, NodeId>
+    for AugmentedBST<Point, Value, ImplTree, NodeId>
+where
+    Point: Ord + Clone,
+    Value: Clone,
+    NodeId: Clone + PartialEq,
+    ImplTree: Default
+        + Tree<Interval<Point>, Augmented<Point, Value>>
+        + NodeIdentifiableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ParentifiedTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + RotatableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ObservableTree<Interval<Point>, NodeId>,
+{
+    fn get_parent_id(&self, node_id: NodeId) -> Option<NodeId> {
+        self.bst.get_parent_id(node_id)
+    }
+
+    fn get_parent(&mut self, node_id: NodeId) -> Option<GuardRef<'_, ImplTree::Guards, Value>> {
+        self.bst
+            .get_parent(node_id)
+            .map(|data| <ImplTree::Guards as Guards>::map(data, |data| &data.value))
+    }
+
+    fn get_parent_mut(
+        &mut self,
+        node_id: NodeId,
+    ) -> Option<GuardRefMut<'_, ImplTree::Guards, Value>> {
+        self.bst
+            .get_parent_mut(node_id)
+            .map(|data| <ImplTree::Guards as Guards>::map_mut(data, |data| &mut data.value))
+    }
+
+    fn get_left_uncle_id(&self, node_id: NodeId) -> Option<NodeId> {
+        self.bst.get_left_uncle_id(node_id)
+    }
+
+    fn get_right_uncle_id(&self, node_id: NodeId) -> Option<NodeId> {
+        self.bst.get_right_uncle_id(node_id)
+    }
+
+    fn get_left_uncle_mut(
+        &mut self,
+        node_id: NodeId,
+    ) -> Option<GuardRefMut<'_, ImplTree::Guards, Value>> {
+        self.bst
+            .get_left_uncle_mut(node_id)
+            .map(|data| <ImplTree::Guards as Guards>::map_mut(data, |data| &mut data.value))
+    }
+
+    fn get_right_uncle_mut(
+        &mut self,
+        node_id: NodeId,
+    ) -> Option<GuardRefMut<'_, ImplTree::Guards, Value>> {
+        self.bst
+            .get_right_uncle_mut(node_id)
+            .map(|data| <ImplTree::Guards as Guards>::map_mut(data, |data| &mut data.value))
+    }
+
+    fn is_left_son(&self, node_id: NodeId) -> bool {
+        self.bst.is_left_son(node_id)
+    }
+
+    fn is_right_son(&self, node_id: NodeId) -> bool {
+        self.bst.is_right_son(node_id)
+    }
+}
+
+impl<Point, Value, ImplTree, NodeId> RotatableTree<Interval<Point>, Value, NodeId>
+    for AugmentedBST<Point, Value, ImplTree, NodeId>
+where
+    Point: Ord + Clone,
+    Value: Clone,
+    NodeId: Clone + PartialEq,
+    ImplTree: Default
+        + Tree<Interval<Point>, Augmented<Point, Value>>
+        + NodeIdentifiableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ParentifiedTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + RotatableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ObservableTree<Interval<Point>, NodeId>,
+{
+    fn right_rotate(&mut self, node_id: NodeId) {
+        self.bst.right_rotate(node_id.clone());
+        self.update_max_end(node_id.clone());
+
+        if let Some(parent_id) = self.bst.get_parent_id(node_id) {
+            self.update_max_end(parent_id);
+        }
+    }
+
+    fn left_rotate(&mut self, node_id: NodeId) {
+        self.bst.left_rotate(node_id.clone());
+        self.update_max_end(node_id.clone());
+
+        if let Some(parent_id) = self.bst.get_parent_id(node_id) {
+            self.update_max_end(parent_id);
+        }
+    }
+}
+
+impl<Point, Value, ImplTree, NodeId> ObservableTree<Interval<Point>, NodeId>
+    for AugmentedBST<Point, Value, ImplTree, NodeId>
+where
+    Point: Ord + Clone,
+    Value: Clone,
+    NodeId: Clone + PartialEq,
+    ImplTree: Default
+        + Tree<Interval<Point>, Augmented<Point, Value>>
+        + NodeIdentifiableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ParentifiedTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + RotatableTree<Interval<Point>, Augmented<Point, Value>, NodeId>
+        + ObservableTree<Interval<Point>, NodeId>,
+{
+    type Observer = ImplTree::Observer;
+
+    fn with_observer(observer: Self::Observer) -> Self {
+        Self {
+            bst: ImplTree::with_observer(observer),
+            ..Default::default()
+        }
+    }
+
+    fn observer(&self) -> &Self::Observer {
+        self.bst.observer()
+    }
+
+    fn observer_mut(&mut self) -> &mut Self::Observer {
+        self.bst.observer_mut()
+    }
+
+    fn notify(&mut self, node_id: NodeId, event: Event) {
+        self.bst.notify(node_id, event);
+    }
+}
+
+type AugmentedRedBlackTree<Point, Value, ImplTree, NodeId> = RedBlackTree<
+    Interval<Point>,
+    Value,
+    AugmentedBST<Point, Data<Value>, ImplTree, NodeId>,
+    NodeId,
+>;
+
+pub struct IntervalTree<Point, Value, ImplTree, NodeId>
+where
+    Point: Ord + Clone,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Interval<Point>, Augmented<Point, Data<Value>>>
+        + NodeIdentifiableTree<Interval<Point>, Augmented<Point, Data<Value>>, NodeId>
+        + ParentifiedTree<Interval<Point>, Augmented<Point, Data<Value>>, NodeId>
+        + RotatableTree<Interval<Point>, Augmented<Point, Data<Value>>, NodeId>
+        + ObservableTree<Interval<Point>, NodeId>,
+{
+    rb_tree: AugmentedRedBlackTree<Point, Value, ImplTree, NodeId>,
+}
+
+impl<Point, Value, ImplTree, NodeId> Default for IntervalTree<Point, Value, ImplTree, NodeId>
+where
+    Point: Ord + Clone,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Interval<Point>, Augmented<Point, Data<Value>>>
+        + NodeIdentifiableTree<Interval<Point>, Augmented<Point, Data<Value>>, NodeId>
+        + ParentifiedTree<Interval<Point>, Augmented<Point, Data<Value>>, NodeId>
+        + RotatableTree<Interval<Point>, Augmented<Point, Data<Value>>, NodeId>
+        + ObservableTree<Interval<Point>, NodeId>,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            rb_tree: Default::default(),
+        }
+    }
+}
+
+impl<Point, Value, ImplTree, NodeId> IntervalTree<Point, Value, ImplTree, NodeId>
+where
+    Point: Ord + Clone,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Interval<Point>, Augmented<Point, Data<Value>>>
+        + NodeIdentifiableTree<Interval<Point>, Augmented<Point, Data<Value>>, NodeId>
+        + ParentifiedTree<Interval<Point>, Augmented<Point, Data<Value>>, NodeId>
+        + RotatableTree<Interval<Point>, Augmented<Point, Data<Value>>, NodeId>
+        + ObservableTree<Interval<Point>, NodeId>,
+{
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn len(&self) -> usize {
+        let bst = self.rb_tree.bst();
+        bst.get_root_id()
+            .map_or(0, |root_id| bst.get_subtree_size(root_id))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rb_tree.bst().get_root_id().is_none()
+    }
+
+    fn get_entry(&self, node_id: NodeId) -> (Interval<Point>, Value) {
+        let bst = self.rb_tree.bst();
+        let key = bst.get_key_by_id(node_id.clone()).expect("Node not found");
+        let data = bst.get_by_id(node_id).expect("Node not found");
+
+        (key.clone(), data.value().clone())
+    }
+
+    pub fn find_overlap(&self, interval: &Interval<Point>) -> Option<(Interval<Point>, Value)> {
+        let bst = self.rb_tree.bst();
+        let mut maybe_node_id = bst.get_root_id();
+
+        while let Some(node_id) = maybe_node_id {
+            let key = bst.get_key_by_id(node_id.clone()).expect("Node not found");
+            if key.overlaps(interval) {
+                return Some(self.get_entry(node_id));
+            }
+
+            let left_son_id = bst.get_left_son_id(node_id.clone());
+            maybe_node_id = match left_son_id {
+                Some(left_son_id)
+                    if *bst.get_max_end(left_son_id.clone()).expect("Son not found")
+                        >= interval.start =>
+                {
+                    Some(left_son_id)
+                }
+                _ => bst.get_right_son_id(node_id),
+            };
+        }
+
+        None
+    }
+
+    pub fn overlapping(&self, interval: &Interval<Point>) -> Vec<(Interval<Point>, Value)> {
+        let mut result = Vec::new();
+        self.collect_overlapping(self.rb_tree.bst().get_root_id(), interval, &mut result);
+        result
+    }
+
+    pub fn stabbing(&self, point: &Point) -> Vec<(Interval<Point>, Value)> {
+        self.overlapping(&Interval::new(point.clone(), point.clone()))
+    }
+
+    fn collect_overlapping(
+        &self,
+        node_id: Option<NodeId>,
+        interval: &Interval<Point>,
+        result: &mut Vec<(Interval<Point>, Value)>,
+    ) {
+        let bst = self.rb_tree.bst();
+        let Some(node_id) = node_id.filter(|node_id| {
+            *bst.get_max_end(node_id.clone()).expect("Node not found") >= interval.start
+        }) else {
+            return;
+        };
+
+        self.collect_overlapping(bst.get_left_son_id(node_id.clone()), interval, result);
+
+        let key = bst.get_key_by_id(node_id.clone()).expect("Node not found");
+        if key.start <= interval.end {
+            if key.overlaps(interval) {
+                result.push(self.get_entry(node_id.clone()));
+            }
+
+            self.collect_overlapping(bst.get_right_son_id(node_id), interval, result);
+        }
+    }
+
+    pub fn validate(&self) -> Result<usize, Violation<NodeId>> {
+        let black_height = self.rb_tree.validate().map_err(Violation::RedBlack)?;
+        let bst = self.rb_tree.bst();
+        let mut stack = Vec::from_iter(bst.get_root_id());
+
+        while let Some(node_id) = stack.pop() {
+            let max_end = bst.get_max_end(node_id.clone()).expect("Node not found");
+            if *max_end != bst.compute_max_end(node_id.clone()) {
+                return Err(Violation::WrongMaxEnd(node_id));
+            }
+
+            stack.extend(bst.get_left_son_id(node_id.clone()));
+            stack.extend(bst.get_right_son_id(node_id));
+        }
+
+        Ok(black_height)
+    }
+}
+
+impl<Point, Value, ImplTree, NodeId> Tree<Interval<Point>, Value>
+    for IntervalTree<Point, Value, ImplTree, NodeId>
+where
+    Point: Ord + Clone,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Interval<Point>, Augmented<Point, Data<Value>>>
+        + NodeIdentifiableTree<Interval<Point>, Augmented<Point, Data<Value>>, NodeId>
+        + ParentifiedTree<Interval<Point>, Augmented<Point, Data<Value>>, NodeId>
+        + RotatableTree<Interval<Point>, Augmented<Point, Data<Value>>, NodeId>
+        + ObservableTree<Interval<Point>, NodeId>,
+{
+    fn insert(&mut self, key: Interval<Point>, val: Value) {
+        self.rb_tree.insert(key, val);
+    }
+
+    fn get(&self, key: &Interval<Point>) -> Option<Value> {
+        self.rb_tree.get(key)
+    }
+
+    fn delete(&mut self, key: &Interval<Point>) -> Option<Value> {
+        self.rb_tree.delete(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Interval;
+    use crate::test_utils::XorShift64;
+    use crate::traits::Tree;
+    use crate::{ArrayIntervalTree, ListIntervalTree};
+    use std::collections::BTreeMap;
+
+    fn brute_force(
+        model: &BTreeMap<Interval<u64>, u64>,
+        interval: &Interval<u64>,
+    ) -> Vec<(Interval<u64>, u64)> {
+        model
+            .iter()
+            .filter(|(key, _)| key.overlaps(interval))
+            .map(|(key, value)| (*key, *value))
+            .collect()
+    }
+
+    fn random_interval(random: &mut XorShift64) -> Interval<u64> {
+        let start = random.next_below(1000);
+        Interval::new(start, start + random.next_below(100))
+    }
+
+    macro_rules! interval_tree_tests {
+        ($($backend:ident: $tree:ident,)*) => {$(
+            mod $backend {
+                use super::*;
+
+                #[test]
+                fn test_textbook_example() {
+                    let mut tree = $tree::<i32, usize>::new();
+                    assert!(tree.is_empty());
+                    assert_eq!(tree.find_overlap(&Interval::new(0, 100)), None);
+
+                    let intervals = [
+                        (16, 21), (8, 9), (25, 30), (5, 8), (15, 23),
+                        (17, 19), (26, 26), (0, 3), (6, 10), (19, 20),
+                    ];
+                    for (ndx, &(start, end)) in intervals.iter().enumerate() {
+                        tree.insert(Interval::new(start, end), ndx);
+                        assert!(tree.validate().is_ok());
+                    }
+
+                    assert_eq!(tree.len(), intervals.len());
+                    assert_eq!(tree.find_overlap(&Interval::new(22, 25)), Some((Interval::new(15, 23), 4)));
+                    assert_eq!(tree.find_overlap(&Interval::new(11, 14)), None);
+                    assert_eq!(
+                        tree.stabbing(&26),
+                        vec![(Interval::new(25, 30), 2), (Interval::new(26, 26), 6)]
+                    );
+                    assert_eq!(
+                        tree.overlapping(&Interval::new(8, 16)),
+                        vec![
+                            (Interval::new(5, 8), 3),
+                            (Interval::new(6, 10), 8),
+                            (Interval::new(8, 9), 1),
+                            (Interval::new(15, 23), 4),
+                            (Interval::new(16, 21), 0),
+                        ]
+                    );
+
+                    assert_eq!(tree.delete(&Interval::new(15, 23)), Some(4));
+                    assert_eq!(tree.delete(&Interval::new(15, 23)), None);
+                    assert!(tree.validate().is_ok());
+                    assert_eq!(tree.find_overlap(&Interval::new(22, 24)), None);
+                    assert_eq!(tree.get(&Interval::new(6, 10)), Some(8));
+                }
+
+                #[test]
+                fn test_against_brute_force() {
+                    for seed in 1..=4 {
+                        let mut random = XorShift64::new(seed);
+                        let mut tree = $tree::<u64, u64>::new();
+                        let mut model = BTreeMap::new();
+                        let mut keys = Vec::new();
+
+                        for step in 0..1000 {
+                            if keys.is_empty() || random.next_below(3) > 0 {
+                                let interval = random_interval(&mut random);
+                                tree.insert(interval, step);
+                                if model.insert(interval, step).is_none() {
+                                    keys.push(interval);
+                                }
+                            } else {
+                                let ndx = random.next_below(keys.len() as u64) as usize;
+                                let interval = keys.swap_remove(ndx);
+                                assert_eq!(tree.delete(&interval), model.remove(&interval));
+                            }
+
+                            assert!(tree.validate().is_ok(), "seed {}, step {}", seed, step);
+                            assert_eq!(tree.len(), model.len());
+
+                            let query = random_interval(&mut random);
+                            let expected = brute_force(&model, &query);
+                            assert_eq!(tree.overlapping(&query), expected);
+                            assert_eq!(tree.find_overlap(&query).is_some(), !expected.is_empty());
+                            if let Some(found) = tree.find_overlap(&query) {
+                                assert!(expected.contains(&found));
+                            }
+
+                            let point = random.next_below(1100);
+                            let expected = brute_force(&model, &Interval::new(point, point));
+                            assert_eq!(tree.stabbing(&point), expected);
+                        }
+                    }
+                }
+            }
+        )*};
+    }
+
+    interval_tree_tests! {
+        array_backend: ArrayIntervalTree,
+        list_backend: ListIntervalTree,
+    }
+
+    #[test]
+    fn test_interval_accessors() {
+        let interval = Interval::new(3, 7);
+        assert_eq!((interval.start(), interval.end()), (&3, &7));
+        assert!(interval.contains(&7));
+        assert!(!interval.overlaps(&Interval::new(8, 9)));
+        assert_eq!(interval.to_string(), "[3, 7]");
+    }
+
+    #[test]
+    #[should_panic(expected = "Interval start must not exceed its end")]
+    fn test_reversed_interval() {
+        Interval::new(2, 1);
+    }
+}
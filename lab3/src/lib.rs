@@ -0,0 +1,86 @@
+#![cfg_attr(all(test, feature = "bench"), feature(test))]
+#![forbid(unsafe_code)]
+
+use crate::array_bst::ArrayBST;
+use crate::avl_tree::AvlTree;
+use crate::interval_tree::{Augmented, Interval, IntervalTree};
+use crate::list_bst::ListBST;
+use crate::red_black_tree::RedBlackTree;
+use crate::splay_tree::SplayTree;
+use crate::treap::Treap;
+
+pub mod array_bst;
+pub mod avl_tree;
+pub mod b_plus_tree;
+pub mod b_tree;
+pub mod benchmark_suite;
+pub mod concurrent_red_black_tree;
+pub mod export;
+pub mod guards;
+pub mod interval_tree;
+pub mod list_bst;
+pub mod observer;
+pub mod ordered_set;
+mod ordered_tree;
+pub mod persistent_red_black_tree;
+pub mod random;
+pub mod red_black_tree;
+pub mod splay_tree;
+pub mod traits;
+pub mod traversal;
+pub mod treap;
+
+#[cfg(test)]
+mod test_utils;
+
+#[cfg(all(test, feature = "bench"))]
+extern crate test;
+
+#[cfg(all(test, feature = "bench"))]
+mod benchmarks;
+
+pub type ArrayRedBlackTree<Key, Value> =
+    RedBlackTree<Key, Value, ArrayBST<Key, red_black_tree::Data<Value>>, array_bst::NodeId>;
+
+pub type ListRedBlackTree<Key, Value> = RedBlackTree<
+    Key,
+    Value,
+    ListBST<Key, red_black_tree::Data<Value>>,
+    list_bst::NodeId<Key, red_black_tree::Data<Value>>,
+>;
+
+pub type ArrayAvlTree<Key, Value> =
+    AvlTree<Key, Value, ArrayBST<Key, avl_tree::Data<Value>>, array_bst::NodeId>;
+
+pub type ListAvlTree<Key, Value> = AvlTree<
+    Key,
+    Value,
+    ListBST<Key, avl_tree::Data<Value>>,
+    list_bst::NodeId<Key, avl_tree::Data<Value>>,
+>;
+
+pub type ArrayTreap<Key, Value> =
+    Treap<Key, Value, ArrayBST<Key, treap::Data<Value>>, array_bst::NodeId>;
+
+pub type ListTreap<Key, Value> =
+    Treap<Key, Value, ListBST<Key, treap::Data<Value>>, list_bst::NodeId<Key, treap::Data<Value>>>;
+
+pub type ArraySplayTree<Key, Value> =
+    SplayTree<Key, Value, ArrayBST<Key, Value>, array_bst::NodeId>;
+
+pub type ListSplayTree<Key, Value> =
+    SplayTree<Key, Value, ListBST<Key, Value>, list_bst::NodeId<Key, Value>>;
+
+pub type ArrayIntervalTree<Point, Value> = IntervalTree<
+    Point,
+    Value,
+    ArrayBST<Interval<Point>, Augmented<Point, red_black_tree::Data<Value>>>,
+    array_bst::NodeId,
+>;
+
+pub type ListIntervalTree<Point, Value> = IntervalTree<
+    Point,
+    Value,
+    ListBST<Interval<Point>, Augmented<Point, red_black_tree::Data<Value>>>,
+    list_bst::NodeId<Interval<Point>, Augmented<Point, red_black_tree::Data<Value>>>,
+>;
@@ -1,27 +1,71 @@
-use std::cell::{Ref, RefCell, RefMut};
+use std::cell::{Cell, Ref, RefCell, RefMut};
+use std::ops::RangeBounds;
+use std::str::FromStr;
 use std::rc::{Rc, Weak};
-use crate::traits::{NodeIdentifiableTree, ParentifiedTree, RotatableTree, Tree};
+use crate::export::{self, LoadError};
+use crate::guards::CellGuards;
+use crate::ordered_tree::{self, RangeIter};
+use crate::observer::{Event, NoObserver, TreeObserver};
+use crate::traits::{
+    BulkLoadableTree, NodeIdentifiableTree, ObservableTree, OrderedTree, ParentifiedTree,
+    RotatableTree, TraversableTree, Tree,
+};
+use crate::traversal::{InOrder, Traverse};
 
-#[derive(Clone)]
-pub(crate) struct NodeId<Key, Value> {
-    node: Weak<RefCell<Node<Key, Value>>>
+pub struct NodeId<Key, Value> {
+    node: Weak<Node<Key, Value>>
+}
+
+impl<Key, Value> Clone for NodeId<Key, Value> {
+    fn clone(&self) -> Self {
+        Self {
+            node: self.node.clone(),
+        }
+    }
 }
 
 impl<Key, Value> NodeId<Key, Value> {
-    fn new(node: Rc<RefCell<Node<Key, Value>>>) -> Self {
+    fn new(node: &Rc<Node<Key, Value>>) -> Self {
+        Self {
+            node: Rc::downgrade(node)
+        }
+    }
+}
+
+impl<Key, Value> Default for NodeId<Key, Value> {
+    fn default() -> Self {
         Self {
-            node: Rc::downgrade(&node)
+            node: Weak::new(),
         }
     }
 }
 
+impl<Key, Value> PartialEq for NodeId<Key, Value> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node.ptr_eq(&other.node)
+    }
+}
+
+impl<Key, Value> Eq for NodeId<Key, Value> {}
+
+impl<Key, Value> std::fmt::Debug for NodeId<Key, Value> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "NodeId({:p})", self.node.as_ptr())
+    }
+}
+
 struct Node<Key, Value> {
     key: Key,
     val: RefCell<Value>,
-    // id: NodeId<Key, Value>,
+    node_ndx: Cell<usize>,
+    links: RefCell<Links<Key, Value>>,
+}
+
+struct Links<Key, Value> {
     parent_id: Option<NodeId<Key, Value>>,
-    left_son_id: Option<Rc<RefCell<Node<Key, Value>>>>,
-    right_son_id: Option<Rc<RefCell<Node<Key, Value>>>>,
+    left_son_id: Option<Rc<Node<Key, Value>>>,
+    right_son_id: Option<Rc<Node<Key, Value>>>,
+    size: usize,
 }
 
 enum SonType {
@@ -35,49 +79,182 @@ enum InsertionPlace<Key, Value> {
     NodeAlreadyExists(NodeId<Key, Value>),
 }
 
-#[derive(Default)]
-pub struct ListBST<Key, Value>
+pub struct ListBST<Key, Value, Observer = NoObserver>
 where
     Key: Ord,
     Value: Clone,
+    Observer: TreeObserver<Key>,
 {
-    root: Option<Rc<RefCell<Node<Key, Value>>>>,
+    root: Option<Rc<Node<Key, Value>>>,
+    nodes: Vec<Rc<Node<Key, Value>>>,
+    observer: Observer,
+}
+
+impl<Key, Value, Observer> Default for ListBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key> + Default,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            root: None,
+            nodes: Default::default(),
+            observer: Default::default(),
+        }
+    }
 }
 
 impl<Key, Value> ListBST<Key, Value>
 where
     Key: Ord,
     Value: Clone,
+{
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl<Key, Value, Observer> ListBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
 {
     fn find_insertion_place(&self, key: &Key) -> InsertionPlace<Key, Value> {
         let mut maybe_current_node = self.root.clone();
 
         while let Some(current_node) = maybe_current_node.clone() {
-            if *key < current_node.borrow().key {
-                if let Some(left_son) = current_node.borrow().left_son_id.clone() {
+            if *key < current_node.key {
+                if let Some(left_son) = current_node.links.borrow().left_son_id.clone() {
                     maybe_current_node = Some(left_son);
                 } else {
-                    return InsertionPlace::SonOf(NodeId::new(current_node.clone()), SonType::Left);
+                    return InsertionPlace::SonOf(NodeId::new(&current_node), SonType::Left);
                 }
-            } else if *key > current_node.borrow().key {
-                if let Some(right_son) = current_node.borrow().right_son_id.clone() {
+            } else if *key > current_node.key {
+                if let Some(right_son) = current_node.links.borrow().right_son_id.clone() {
                     maybe_current_node = Some(right_son);
                 } else {
-                    return InsertionPlace::SonOf(NodeId::new(current_node.clone()), SonType::Right);
+                    return InsertionPlace::SonOf(NodeId::new(&current_node), SonType::Right);
                 }
             } else {
-                return InsertionPlace::NodeAlreadyExists(NodeId::new(current_node.clone()));
+                return InsertionPlace::NodeAlreadyExists(NodeId::new(&current_node));
             }
         }
 
         InsertionPlace::Root
     }
+
+    fn get_node_by_id(&self, node_id: &NodeId<Key, Value>) -> Option<&Rc<Node<Key, Value>>> {
+        let node = node_id.node.upgrade()?;
+        self.nodes
+            .get(node.node_ndx.get())
+            .filter(|owned| Rc::ptr_eq(owned, &node))
+    }
+
+    fn allocate_node(
+        &mut self,
+        key: Key,
+        val: Value,
+        parent_id: Option<NodeId<Key, Value>>,
+        size: usize,
+    ) -> Rc<Node<Key, Value>> {
+        let node = Rc::new(Node {
+            key,
+            val: RefCell::new(val),
+            node_ndx: Cell::new(self.nodes.len()),
+            links: RefCell::new(Links {
+                parent_id,
+                left_son_id: None,
+                right_son_id: None,
+                size,
+            }),
+        });
+
+        self.nodes.push(node.clone());
+        node
+    }
+
+    fn free_node(&mut self, node: &Rc<Node<Key, Value>>) {
+        let node_ndx = node.node_ndx.get();
+        self.nodes.swap_remove(node_ndx);
+
+        if let Some(moved) = self.nodes.get(node_ndx) {
+            moved.node_ndx.set(node_ndx);
+        }
+    }
+
+    fn get_minimum(mut node: Rc<Node<Key, Value>>) -> Rc<Node<Key, Value>> {
+        loop {
+            let left_son = node.links.borrow().left_son_id.clone();
+            match left_son {
+                Some(left_son) => node = left_son,
+                None => return node,
+            }
+        }
+    }
+
+    fn get_size(node: &Option<Rc<Node<Key, Value>>>) -> usize {
+        node.as_ref().map_or(0, |node| node.links.borrow().size)
+    }
+
+    fn update_size(node: &Rc<Node<Key, Value>>) {
+        let size = {
+            let links = node.links.borrow();
+            1 + Self::get_size(&links.left_son_id) + Self::get_size(&links.right_son_id)
+        };
+
+        node.links.borrow_mut().size = size;
+    }
+
+    fn update_sizes_upwards(mut maybe_node: Option<Rc<Node<Key, Value>>>) {
+        while let Some(node) = maybe_node {
+            Self::update_size(&node);
+            maybe_node = node
+                .links
+                .borrow()
+                .parent_id
+                .as_ref()
+                .and_then(|parent_id| parent_id.node.upgrade());
+        }
+    }
+
+    fn transplant(
+        &mut self,
+        node: &Rc<Node<Key, Value>>,
+        replacement: Option<Rc<Node<Key, Value>>>,
+    ) {
+        let parent_id = node.links.borrow().parent_id.clone();
+
+        match parent_id.as_ref().and_then(|parent_id| parent_id.node.upgrade()) {
+            Some(parent) => {
+                let mut parent = parent.links.borrow_mut();
+                let is_left_son = parent
+                    .left_son_id
+                    .as_ref()
+                    .is_some_and(|left_son| Rc::ptr_eq(left_son, node));
+
+                if is_left_son {
+                    parent.left_son_id = replacement.clone();
+                } else {
+                    parent.right_son_id = replacement.clone();
+                }
+            }
+            None => self.root = replacement.clone(),
+        }
+
+        if let Some(replacement) = replacement {
+            replacement.links.borrow_mut().parent_id = parent_id;
+        }
+    }
 }
 
-impl<Key, Value> Tree<Key, Value> for ListBST<Key, Value>
+impl<Key, Value, Observer> Tree<Key, Value> for ListBST<Key, Value, Observer>
 where
     Key: Ord,
     Value: Clone,
+    Observer: TreeObserver<Key>,
 {
     fn insert(&mut self, key: Key, val: Value) {
         let _ = self.insert_and_get_id(key, val);
@@ -88,184 +265,613 @@ where
             InsertionPlace::NodeAlreadyExists(node_id) => {
                 node_id.node
                     .upgrade()
-                    .map(|node| node.borrow().val.borrow().clone())
+                    .map(|node| node.val.borrow().clone())
             }
             _ => None,
         }
     }
 
     fn delete(&mut self, key: &Key) -> Option<Value> {
-        todo!()
+        let node_id = self.get_id(key)?;
+        self.delete_by_id(node_id)
     }
 }
 
-impl<Key, Value> NodeIdentifiableTree<Key, Value, NodeId<Key, Value>> for ListBST<Key, Value>
+impl<Key, Value, Observer> NodeIdentifiableTree<Key, Value, NodeId<Key, Value>>
+    for ListBST<Key, Value, Observer>
 where
     Key: Ord,
     Value: Clone,
+    Observer: TreeObserver<Key>,
 {
+    type Guards = CellGuards;
+
     fn insert_and_get_id(&mut self, key: Key, val: Value) -> NodeId<Key, Value> {
         let inserted_node_id = match self.find_insertion_place(&key) {
             InsertionPlace::Root => {
-                self.root = Some(Rc::new(RefCell::new(Node {
-                    key,
-                    val: RefCell::new(val),
-                    parent_id: None,
-                    left_son_id: None,
-                    right_son_id: None,
-                })));
-
-                NodeId {
-                    node: Rc::downgrade(&self.root.clone().unwrap())
-                }
+                let root = self.allocate_node(key, val, None, 1);
+                self.root = Some(root.clone());
+                NodeId::new(&root)
             }
             InsertionPlace::SonOf(parent_id, son_type) => {
                 let parent = parent_id.node.upgrade().expect("This Rc must live longer!");
-                let new_node = Rc::new(RefCell::new(Node {
-                    key,
-                    val: RefCell::new(val),
-                    parent_id: Some(parent_id),
-                    left_son_id: None,
-                    right_son_id: None,
-                }));
+                let new_node = self.allocate_node(key, val, Some(parent_id), 1);
 
                 match son_type {
                     SonType::Left => {
-                        parent.borrow_mut().left_son_id = Some(new_node.clone());
+                        parent.links.borrow_mut().left_son_id = Some(new_node.clone());
                     }
                     SonType::Right => {
-                        parent.borrow_mut().right_son_id = Some(new_node.clone());
+                        parent.links.borrow_mut().right_son_id = Some(new_node.clone());
                     }
                 }
 
-                NodeId {
-                    node: Rc::downgrade(&new_node)
-                }
+                Self::update_sizes_upwards(Some(parent));
+
+                NodeId::new(&new_node)
             }
             InsertionPlace::NodeAlreadyExists(node_id) => {
                 let node = node_id.node.upgrade().expect("This Rc must live longer!");
-                node.borrow_mut().key = key;
-                *node.borrow_mut().val.borrow_mut() = val;
-                node_id
+                *node.val.borrow_mut() = val;
+                self.notify(node_id.clone(), Event::Replace);
+                return node_id;
             }
         };
 
+        self.notify(inserted_node_id.clone(), Event::Insert);
         inserted_node_id
     }
 
-    fn get_by_id(&self, node_id: NodeId<Key, Value>) -> Option<Ref<Value>> {
-        // node_id
-        //     .node
-        //     .upgrade()
-        //     .map(|node| Ref::map(node.borrow(), |inner| &inner.val))
+    fn get_id(&self, key: &Key) -> Option<NodeId<Key, Value>> {
+        match self.find_insertion_place(key) {
+            InsertionPlace::NodeAlreadyExists(node_id) => Some(node_id),
+            _ => None,
+        }
+    }
 
-        if let Some(node) = node_id.node.upgrade() {
-            Some(node.borrow().val.borrow())
-        } else {
-            None
+    fn delete_by_id(&mut self, node_id: NodeId<Key, Value>) -> Option<Value> {
+        let node = self.get_node_by_id(&node_id)?.clone();
+        self.notify(node_id, Event::Delete);
+        let (left_son, right_son) = {
+            let links = node.links.borrow();
+            (links.left_son_id.clone(), links.right_son_id.clone())
+        };
+
+        let parent = node
+            .links
+            .borrow()
+            .parent_id
+            .as_ref()
+            .and_then(|parent_id| parent_id.node.upgrade());
+
+        let resized = match (left_son, right_son) {
+            (None, right_son) => {
+                self.transplant(&node, right_son);
+                parent
+            }
+            (left_son, None) => {
+                self.transplant(&node, left_son);
+                parent
+            }
+            (Some(left_son), Some(right_son)) => {
+                let successor = Self::get_minimum(right_son.clone());
+                let resized = if Rc::ptr_eq(&successor, &right_son) {
+                    Some(successor.clone())
+                } else {
+                    successor
+                        .links
+                        .borrow()
+                        .parent_id
+                        .as_ref()
+                        .and_then(|parent_id| parent_id.node.upgrade())
+                };
+
+                if !Rc::ptr_eq(&successor, &right_son) {
+                    let successor_right_son = successor.links.borrow().right_son_id.clone();
+                    self.transplant(&successor, successor_right_son);
+
+                    successor.links.borrow_mut().right_son_id = Some(right_son.clone());
+                    right_son.links.borrow_mut().parent_id = Some(NodeId::new(&successor));
+                }
+
+                self.transplant(&node, Some(successor.clone()));
+
+                successor.links.borrow_mut().left_son_id = Some(left_son.clone());
+                left_son.links.borrow_mut().parent_id = Some(NodeId::new(&successor));
+
+                resized
+            }
+        };
+
+        Self::update_sizes_upwards(resized);
+
+        {
+            let mut links = node.links.borrow_mut();
+            links.parent_id = None;
+            links.left_son_id = None;
+            links.right_son_id = None;
+        }
+        self.free_node(&node);
+
+        match Rc::try_unwrap(node) {
+            Ok(node) => Some(node.val.into_inner()),
+            Err(node) => Some(node.val.borrow().clone()),
         }
     }
 
-    fn get_by_id_mut(&mut self, node_id: NodeId<Key, Value>) -> Option<RefMut<Value>> {
-        // node_id
-        //     .node
-        //     .upgrade()
-        //     .map(|node| RefMut::map(node.borrow_mut(), |inner| &mut inner.val))
-        todo!()
+    fn get_key_by_id(&self, node_id: NodeId<Key, Value>) -> Option<&Key> {
+        Some(&self.get_node_by_id(&node_id)?.key)
+    }
+
+    fn get_subtree_size(&self, node_id: NodeId<Key, Value>) -> usize {
+        self.get_node_by_id(&node_id)
+            .map_or(0, |node| node.links.borrow().size)
+    }
+
+    fn get_by_id(&self, node_id: NodeId<Key, Value>) -> Option<Ref<'_, Value>> {
+        Some(self.get_node_by_id(&node_id)?.val.borrow())
+    }
+
+    fn get_by_id_mut(&mut self, node_id: NodeId<Key, Value>) -> Option<RefMut<'_, Value>> {
+        Some(self.get_node_by_id(&node_id)?.val.borrow_mut())
+    }
+
+    fn get_entry_by_id_mut(
+        &mut self,
+        node_id: NodeId<Key, Value>,
+    ) -> Option<(&Key, RefMut<'_, Value>)> {
+        let node = self.get_node_by_id(&node_id)?;
+        Some((&node.key, node.val.borrow_mut()))
     }
 
     fn get_left_son_id(&self, node_id: NodeId<Key, Value>) -> Option<NodeId<Key, Value>> {
-        todo!()
+        self.get_node_by_id(&node_id)?
+            .links
+            .borrow()
+            .left_son_id
+            .as_ref()
+            .map(NodeId::new)
     }
 
     fn get_right_son_id(&self, node_id: NodeId<Key, Value>) -> Option<NodeId<Key, Value>> {
-        todo!()
+        self.get_node_by_id(&node_id)?
+            .links
+            .borrow()
+            .right_son_id
+            .as_ref()
+            .map(NodeId::new)
     }
 
     fn get_root_id(&self) -> Option<NodeId<Key, Value>> {
-        todo!()
+        self.root.as_ref().map(NodeId::new)
     }
 
-    fn get_left_son_mut(&mut self, node_id: NodeId<Key, Value>) -> Option<RefMut<Value>> {
-        todo!()
+    fn get_left_son_mut(&mut self, node_id: NodeId<Key, Value>) -> Option<RefMut<'_, Value>> {
+        self.get_by_id_mut(self.get_left_son_id(node_id)?)
     }
 
-    fn get_right_son_mut(&mut self, node_id: NodeId<Key, Value>) -> Option<RefMut<Value>> {
-        todo!()
+    fn get_right_son_mut(&mut self, node_id: NodeId<Key, Value>) -> Option<RefMut<'_, Value>> {
+        self.get_by_id_mut(self.get_right_son_id(node_id)?)
     }
 
-    fn get_root_mut(&mut self) -> Option<RefMut<Value>> {
-        todo!()
+    fn get_root_mut(&mut self) -> Option<RefMut<'_, Value>> {
+        self.get_by_id_mut(self.get_root_id()?)
     }
 
-    fn modify<F>(&mut self, node_id: NodeId<Key, Value>, modifier: F)
+    fn modify<F>(&mut self, node_id: NodeId<Key, Value>, mut modifier: F)
     where
-        F: FnMut(RefMut<Value>)
+        F: FnMut(&mut Value)
     {
-        todo!()
+        if let Some(mut val) = self.get_by_id_mut(node_id) {
+            modifier(&mut val);
+        }
     }
 }
 
-impl<Key, Value> ParentifiedTree<Key, Value, NodeId<Key, Value>> for ListBST<Key, Value>
+impl<Key, Value, Observer> ParentifiedTree<Key, Value, NodeId<Key, Value>>
+    for ListBST<Key, Value, Observer>
 where
     Key: Ord,
     Value: Clone,
+    Observer: TreeObserver<Key>,
 {
     fn get_parent_id(&self, node_id: NodeId<Key, Value>) -> Option<NodeId<Key, Value>> {
-        todo!()
+        self.get_node_by_id(&node_id)?.links.borrow().parent_id.clone()
     }
 
-    fn get_parent(&mut self, node_id: NodeId<Key, Value>) -> Option<Ref<Value>> {
-        todo!()
+    fn get_parent(&mut self, node_id: NodeId<Key, Value>) -> Option<Ref<'_, Value>> {
+        self.get_parent_id(node_id)
+            .and_then(|parent_id| self.get_by_id(parent_id))
     }
 
-    fn get_parent_mut(&mut self, node_id: NodeId<Key, Value>) -> Option<RefMut<Value>> {
-        todo!()
+    fn get_parent_mut(&mut self, node_id: NodeId<Key, Value>) -> Option<RefMut<'_, Value>> {
+        self.get_parent_id(node_id)
+            .and_then(|parent_id| self.get_by_id_mut(parent_id))
     }
 
     fn get_left_uncle_id(&self, node_id: NodeId<Key, Value>) -> Option<NodeId<Key, Value>> {
-        todo!()
+        self.get_parent_id(node_id)
+            .and_then(|parent_id| self.get_parent_id(parent_id))
+            .and_then(|grand_parent_id| self.get_left_son_id(grand_parent_id))
     }
 
     fn get_right_uncle_id(&self, node_id: NodeId<Key, Value>) -> Option<NodeId<Key, Value>> {
-        todo!()
+        self.get_parent_id(node_id)
+            .and_then(|parent_id| self.get_parent_id(parent_id))
+            .and_then(|grand_parent_id| self.get_right_son_id(grand_parent_id))
     }
 
-    fn get_left_uncle_mut(&mut self, node_id: NodeId<Key, Value>) -> Option<RefMut<Value>> {
-        todo!()
+    fn get_left_uncle_mut(&mut self, node_id: NodeId<Key, Value>) -> Option<RefMut<'_, Value>> {
+        self.get_left_uncle_id(node_id)
+            .and_then(|uncle_id| self.get_by_id_mut(uncle_id))
     }
 
-    fn get_right_uncle_mut(&mut self, node_id: NodeId<Key, Value>) -> Option<RefMut<Value>> {
-        todo!()
+    fn get_right_uncle_mut(&mut self, node_id: NodeId<Key, Value>) -> Option<RefMut<'_, Value>> {
+        self.get_right_uncle_id(node_id)
+            .and_then(|uncle_id| self.get_by_id_mut(uncle_id))
     }
 
     fn is_left_son(&self, node_id: NodeId<Key, Value>) -> bool {
-        todo!()
+        self.get_parent_id(node_id.clone())
+            .and_then(|parent_id| self.get_left_son_id(parent_id))
+            .is_some_and(|id| id == node_id)
     }
 
     fn is_right_son(&self, node_id: NodeId<Key, Value>) -> bool {
-        todo!()
+        self.get_parent_id(node_id.clone())
+            .and_then(|parent_id| self.get_right_son_id(parent_id))
+            .is_some_and(|id| id == node_id)
     }
 }
 
-impl<Key, Value> RotatableTree<Key, Value, NodeId<Key, Value>> for ListBST<Key, Value>
+impl<Key, Value, Observer> RotatableTree<Key, Value, NodeId<Key, Value>>
+    for ListBST<Key, Value, Observer>
 where
     Key: Ord,
     Value: Clone,
+    Observer: TreeObserver<Key>,
 {
     fn right_rotate(&mut self, node_id: NodeId<Key, Value>) {
-        todo!()
+        self.notify(node_id.clone(), Event::RightRotation);
+        let node = self.get_node_by_id(&node_id).expect("Node not found").clone();
+        let son = node.links.borrow().left_son_id.clone().expect("Son not found");
+
+        let sons_right_son = son.links.borrow_mut().right_son_id.take();
+        if let Some(sons_right_son) = &sons_right_son {
+            sons_right_son.links.borrow_mut().parent_id = Some(NodeId::new(&node));
+        }
+        node.links.borrow_mut().left_son_id = sons_right_son;
+
+        self.transplant(&node, Some(son.clone()));
+
+        son.links.borrow_mut().right_son_id = Some(node.clone());
+        node.links.borrow_mut().parent_id = Some(NodeId::new(&son));
+
+        Self::update_size(&node);
+        Self::update_size(&son);
     }
 
     fn left_rotate(&mut self, node_id: NodeId<Key, Value>) {
-        todo!()
+        self.notify(node_id.clone(), Event::LeftRotation);
+        let node = self.get_node_by_id(&node_id).expect("Node not found").clone();
+        let son = node.links.borrow().right_son_id.clone().expect("Son not found");
+
+        let sons_left_son = son.links.borrow_mut().left_son_id.take();
+        if let Some(sons_left_son) = &sons_left_son {
+            sons_left_son.links.borrow_mut().parent_id = Some(NodeId::new(&node));
+        }
+        node.links.borrow_mut().right_son_id = sons_left_son;
+
+        self.transplant(&node, Some(son.clone()));
+
+        son.links.borrow_mut().left_son_id = Some(node.clone());
+        node.links.borrow_mut().parent_id = Some(NodeId::new(&son));
+
+        Self::update_size(&node);
+        Self::update_size(&son);
+    }
+}
+
+impl<Key, Value, Observer> ObservableTree<Key, NodeId<Key, Value>> for ListBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
+{
+    type Observer = Observer;
+
+    fn with_observer(observer: Observer) -> Self {
+        Self {
+            root: None,
+            nodes: Default::default(),
+            observer,
+        }
+    }
+
+    fn observer(&self) -> &Observer {
+        &self.observer
+    }
+
+    fn observer_mut(&mut self) -> &mut Observer {
+        &mut self.observer
+    }
+
+    fn notify(&mut self, node_id: NodeId<Key, Value>, event: Event) {
+        if let Some(node) = node_id.node.upgrade() {
+            self.observer.on_event(event, &node.key);
+        }
+    }
+}
+
+impl<Key, Value, Observer> OrderedTree<Key, Value> for ListBST<Key, Value, Observer>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
+{
+    fn min(&self) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_min_id(self, self.get_root_id()?))
+    }
+
+    fn max(&self) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_max_id(self, self.get_root_id()?))
+    }
+
+    fn floor(&self, key: &Key) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_floor_id(self, key, true)?)
+    }
+
+    fn ceiling(&self, key: &Key) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_ceiling_id(self, key, true)?)
+    }
+
+    fn successor(&self, key: &Key) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_ceiling_id(self, key, false)?)
+    }
+
+    fn predecessor(&self, key: &Key) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_floor_id(self, key, false)?)
+    }
+
+    fn range<R>(&self, range: R) -> impl Iterator<Item = (Key, Value)>
+    where
+        R: RangeBounds<Key>,
+    {
+        RangeIter::new(self, range)
+    }
+
+    fn rank(&self, key: &Key) -> usize {
+        ordered_tree::get_rank(self, key)
+    }
+
+    fn select(&self, rank: usize) -> Option<(Key, Value)> {
+        ordered_tree::get_entry(self, ordered_tree::get_select_id(self, rank)?)
+    }
+}
+
+impl<Key, Value, Observer> TraversableTree<Key, Value, NodeId<Key, Value>>
+    for ListBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
+{
+}
+
+impl<'a, Key, Value, Observer> IntoIterator for &'a ListBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
+{
+    type Item = (&'a Key, Ref<'a, Value>);
+    type IntoIter = Traverse<
+        'a,
+        Key,
+        Value,
+        NodeId<Key, Value>,
+        ListBST<Key, Value, Observer>,
+        InOrder<NodeId<Key, Value>>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.in_order()
+    }
+}
+
+fn drain_subtree<Key, Value>(
+    root: Option<Rc<Node<Key, Value>>>,
+    entries: &mut Vec<(Key, Value)>,
+) {
+    let mut stack = Vec::new();
+    let mut maybe_node = root;
+
+    loop {
+        while let Some(node) = maybe_node {
+            maybe_node = node.links.borrow_mut().left_son_id.take();
+            stack.push(node);
+        }
+
+        let Some(node) = stack.pop() else {
+            return;
+        };
+        maybe_node = node.links.borrow_mut().right_son_id.take();
+
+        let node = Rc::try_unwrap(node)
+            .ok()
+            .expect("Node must be owned by its parent only");
+        entries.push((node.key, node.val.into_inner()));
+    }
+}
+
+impl<Key, Value, Observer> ListBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
+{
+    fn build_subtree<I>(&mut self, entries: &mut I, len: usize) -> Option<Rc<Node<Key, Value>>>
+    where
+        I: Iterator<Item = (Key, Value)>,
+    {
+        if len == 0 {
+            return None;
+        }
+
+        let left_son = self.build_subtree(entries, len / 2);
+        let (key, val) = entries.next().expect("Entry not found");
+        let node = self.allocate_node(key, val, None, len);
+        let right_son = self.build_subtree(entries, len - len / 2 - 1);
+
+        for son in [&left_son, &right_son].into_iter().flatten() {
+            son.links.borrow_mut().parent_id = Some(NodeId::new(&node));
+        }
+        {
+            let mut links = node.links.borrow_mut();
+            links.left_son_id = left_son;
+            links.right_son_id = right_son;
+        }
+
+        Some(node)
+    }
+
+    fn drain(&mut self) -> Vec<(Key, Value)> {
+        let mut entries = Vec::with_capacity(self.nodes.len());
+        self.nodes.clear();
+        drain_subtree(self.root.take(), &mut entries);
+        entries
+    }
+}
+
+impl<Key, Value, Observer> Drop for ListBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
+{
+    fn drop(&mut self) {
+        // Unlink every node first so that dropping a tall tree does not recurse.
+        for node in &self.nodes {
+            let mut links = node.links.borrow_mut();
+            links.left_son_id = None;
+            links.right_son_id = None;
+        }
+    }
+}
+
+impl<Key, Value, Observer> IntoIterator for ListBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key>,
+{
+    type Item = (Key, Value);
+    type IntoIter = std::vec::IntoIter<(Key, Value)>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        self.drain().into_iter()
+    }
+}
+
+impl<Key, Value, Observer> BulkLoadableTree<Key, Value> for ListBST<Key, Value, Observer>
+where
+    Key: Ord,
+    Value: Clone,
+    Observer: TreeObserver<Key> + Default,
+{
+    fn from_sorted_iter<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (Key, Value)>,
+    {
+        let entries = ordered_tree::collect_sorted(entries);
+        let len = entries.len();
+
+        let mut tree = Self::default();
+        tree.root = tree.build_subtree(&mut entries.into_iter(), len);
+        tree
+    }
+
+    fn rebuild_balanced(&mut self) {
+        let entries = self.drain();
+        let len = entries.len();
+        self.root = self.build_subtree(&mut entries.into_iter(), len);
+    }
+}
+
+impl<Key, Value, Observer> ListBST<Key, Value, Observer>
+where
+    Key: Ord + std::fmt::Display,
+    Value: Clone + std::fmt::Display,
+    Observer: TreeObserver<Key>,
+{
+    pub fn to_dot(&self) -> String {
+        export::to_dot(self, |value| (value.to_string(), ""))
+    }
+
+    pub fn to_json(&self) -> String {
+        export::to_json(self, |value| vec![("value", value.to_string())])
+    }
+}
+
+impl<Key, Value, Observer> ListBST<Key, Value, Observer>
+where
+    Key: Ord + std::fmt::Display + FromStr,
+    Value: Clone + FromStr,
+    Observer: TreeObserver<Key> + Default,
+{
+    pub fn from_json(json: &str) -> Result<Self, LoadError> {
+        export::from_json(json, |fields| fields.parse("value"))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::list_bst::ListBST;
-    use crate::traits::{NodeIdentifiableTree, ParentifiedTree, RotatableTree, Tree};
+    use crate::list_bst::{ListBST, Node, NodeId};
+    use crate::test_utils::{XorShift64, check_bulk_load, check_unsorted_bulk_load, get_height};
+    use crate::traits::{
+        BulkLoadableTree, NodeIdentifiableTree, OrderedTree, ParentifiedTree, RotatableTree, Tree,
+    };
+    use std::collections::BTreeMap;
+    use std::rc::Rc;
+
+    fn check_subtree(
+        nodes: &[Rc<Node<i32, i32>>],
+        node: &Rc<Node<i32, i32>>,
+        parent: Option<&Rc<Node<i32, i32>>>,
+        lower: Option<i32>,
+        upper: Option<i32>,
+    ) -> usize {
+        let links = node.links.borrow();
+        let actual_parent = links
+            .parent_id
+            .as_ref()
+            .and_then(|parent_id| parent_id.node.upgrade());
+
+        match (actual_parent, parent) {
+            (Some(actual_parent), Some(parent)) => assert!(Rc::ptr_eq(&actual_parent, parent)),
+            (None, None) => {}
+            _ => panic!("Broken parent link"),
+        }
+
+        assert!(lower.is_none_or(|lower| node.key > lower));
+        assert!(upper.is_none_or(|upper| node.key < upper));
+        assert!(Rc::ptr_eq(node, &nodes[node.node_ndx.get()]));
+
+        1 + links.left_son_id.as_ref().map_or(0, |left_son| {
+            check_subtree(nodes, left_son, Some(node), lower, Some(node.key))
+        }) + links.right_son_id.as_ref().map_or(0, |right_son| {
+            check_subtree(nodes, right_son, Some(node), Some(node.key), upper)
+        })
+    }
+
+    fn check_bst(tree: &ListBST<i32, i32>) -> usize {
+        let size = tree
+            .root
+            .as_ref()
+            .map_or(0, |root| check_subtree(&tree.nodes, root, None, None, None));
+        assert_eq!(tree.nodes.len(), size);
+        size
+    }
 
     #[test]
     fn test_binary_tree() {
@@ -300,4 +906,169 @@ mod tests {
             assert!(found.is_none());
         }
     }
+
+    #[test]
+    fn test_delete() {
+        let mut tree = ListBST::<i32, i32>::default();
+        for key in [50, 30, 70, 20, 40, 60, 80, 35, 45, 65] {
+            tree.insert(key, key * 10);
+        }
+
+        assert_eq!(tree.delete(&20), Some(200));
+        assert_eq!(tree.delete(&60), Some(600));
+        assert_eq!(tree.delete(&30), Some(300));
+        assert_eq!(tree.delete(&50), Some(500));
+        assert_eq!(tree.delete(&50), None);
+        assert_eq!(check_bst(&tree), 6);
+
+        for key in [35, 40, 45, 65, 70, 80] {
+            assert_eq!(tree.get(&key), Some(key * 10));
+        }
+
+        let id = tree.get_id(&35).unwrap();
+        assert_eq!(tree.delete_by_id(id.clone()), Some(350));
+        assert_eq!(tree.delete_by_id(id), None);
+        assert!(tree.get_id(&35).is_none());
+        assert_eq!(check_bst(&tree), 5);
+    }
+
+    #[test]
+    fn test_random_insert_delete() {
+        let mut random = XorShift64::new(0x1157);
+        let mut tree = ListBST::<i32, i32>::default();
+        let mut model = BTreeMap::new();
+
+        for step in 0..2000 {
+            let key = random.next_below(200) as i32;
+
+            if random.next_below(3) == 0 {
+                assert_eq!(tree.delete(&key), model.remove(&key));
+            } else {
+                tree.insert(key, step);
+                model.insert(key, step);
+            }
+
+            assert_eq!(check_bst(&tree), model.len());
+        }
+
+        for key in 0..200 {
+            assert_eq!(tree.get(&key), model.get(&key).copied());
+        }
+    }
+
+    #[test]
+    fn test_rotations() {
+        let mut tree = ListBST::<i32, i32>::default();
+        let id = tree.insert_and_get_id(5, 50);
+        for key in [3, 1, 4, 8, 6, 9] {
+            tree.insert(key, key * 10);
+        }
+
+        tree.left_rotate(id.clone());
+        assert_eq!(*tree.get_root_mut().unwrap(), 80);
+        assert!(tree.is_left_son(id.clone()));
+        assert_eq!(*tree.get_right_son_mut(id.clone()).unwrap(), 60);
+        assert_eq!(check_bst(&tree), 7);
+
+        tree.right_rotate(tree.get_root_id().unwrap());
+        assert_eq!(tree.get_root_id(), Some(id.clone()));
+        assert_eq!(*tree.get_by_id(id.clone()).unwrap(), 50);
+        assert_eq!(check_bst(&tree), 7);
+
+        let left_son_id = tree.get_left_son_id(id.clone()).unwrap();
+        tree.modify(left_son_id.clone(), |val| *val += 1);
+        assert_eq!(tree.get(&3), Some(31));
+        assert_eq!(tree.get_parent_id(left_son_id.clone()), Some(id));
+        assert_eq!(*tree.get_right_uncle_mut(tree.get_id(&1).unwrap()).unwrap(), 80);
+    }
+
+    #[test]
+    fn test_foreign_node_ids() {
+        let mut tree = ListBST::<i32, i32>::default();
+        let mut other = ListBST::<i32, i32>::default();
+        for key in 0..10 {
+            tree.insert(key, key);
+            other.insert(key, -key);
+        }
+
+        let foreign_id = other.get_id(&5).unwrap();
+        assert_eq!(tree.get_key_by_id(foreign_id.clone()), None);
+        assert_eq!(other.get_key_by_id(foreign_id.clone()), Some(&5));
+        assert!(tree.get_by_id(foreign_id.clone()).is_none());
+        assert!(tree.get_by_id_mut(foreign_id.clone()).is_none());
+        assert!(tree.get_parent_id(foreign_id.clone()).is_none());
+        assert_eq!(tree.get_subtree_size(foreign_id.clone()), 0);
+        assert_eq!(tree.delete_by_id(foreign_id.clone()), None);
+        assert_eq!(tree.delete_by_id(other.get_root_id().unwrap()), None);
+        assert_eq!(tree.get_root_id(), tree.get_id(&0));
+        assert_eq!(check_bst(&tree), 10);
+        assert_eq!(check_bst(&other), 10);
+        assert_eq!(*other.get_by_id(foreign_id.clone()).unwrap(), -5);
+
+        drop(other);
+        assert!(tree.get_by_id(foreign_id).is_none());
+    }
+
+    #[test]
+    fn test_bulk_load() {
+        check_bulk_load(check_bst);
+    }
+
+    #[test]
+    #[should_panic(expected = "Keys must be strictly increasing")]
+    fn test_from_unsorted_iter() {
+        check_unsorted_bulk_load::<ListBST<i32, i32>>();
+    }
+
+    fn degenerate_tree(len: i32) -> ListBST<i32, i32> {
+        let mut tree = ListBST::<i32, i32>::default();
+        let mut maybe_parent: Option<Rc<Node<i32, i32>>> = None;
+
+        for key in 0..len {
+            let parent_id = maybe_parent.as_ref().map(NodeId::new);
+            let node = tree.allocate_node(key, -key, parent_id, (len - key) as usize);
+            match &maybe_parent {
+                Some(parent) => parent.links.borrow_mut().right_son_id = Some(node.clone()),
+                None => tree.root = Some(node.clone()),
+            }
+            maybe_parent = Some(node);
+        }
+
+        tree
+    }
+
+    #[test]
+    fn test_into_iter_degenerate_tree() {
+        let drained = std::thread::Builder::new()
+            .stack_size(2 << 20)
+            .spawn(|| {
+                let tree = degenerate_tree(20_000);
+                tree.into_iter().eq((0..20_000).map(|key| (key, -key)))
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+
+        assert!(drained);
+    }
+
+    #[test]
+    fn test_rebuild_degenerate_tree() {
+        let balanced = std::thread::Builder::new()
+            .stack_size(2 << 20)
+            .spawn(|| {
+                let mut tree = degenerate_tree(20_000);
+                tree.rebuild_balanced();
+
+                check_bst(&tree) == 20_000
+                    && get_height(&tree, tree.get_root_id()) == 15
+                    && tree.select(12_345) == Some((12_345, -12_345))
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+
+        assert!(balanced);
+    }
+
 }
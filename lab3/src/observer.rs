@@ -0,0 +1,141 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    Insert,
+    Replace,
+    LeftRotation,
+    RightRotation,
+    Recolor { red: bool },
+    Delete,
+}
+
+pub trait TreeObserver<Key> {
+    fn on_event(&mut self, event: Event, key: &Key);
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoObserver;
+
+impl<Key> TreeObserver<Key> for NoObserver {
+    #[inline(always)]
+    fn on_event(&mut self, _event: Event, _key: &Key) {}
+}
+
+impl<Key> TreeObserver<Key> for Vec<(Event, Key)>
+where
+    Key: Clone,
+{
+    fn on_event(&mut self, event: Event, key: &Key) {
+        self.push((event, key.clone()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array_bst::{self, ArrayBST};
+    use crate::list_bst::{self, ListBST};
+    use crate::red_black_tree::{Data, RedBlackTree};
+    use crate::traits::{NodeIdentifiableTree, ObservableTree, RotatableTree, Tree};
+
+    type Log = Vec<(Event, i32)>;
+
+    #[derive(Default)]
+    struct RotationCounter {
+        rotations: usize,
+    }
+
+    impl<Key> TreeObserver<Key> for RotationCounter {
+        fn on_event(&mut self, event: Event, _key: &Key) {
+            if matches!(event, Event::LeftRotation | Event::RightRotation) {
+                self.rotations += 1;
+            }
+        }
+    }
+
+    macro_rules! observer_tests {
+        ($($backend:ident: $bst:ident, $node_id:ty,)*) => {$(
+            mod $backend {
+                use super::*;
+
+                #[test]
+                fn test_bst_events() {
+                    let mut tree = $bst::<i32, i32, Log>::with_observer(Vec::new());
+                    let root_id = tree.insert_and_get_id(5, 50);
+                    tree.insert(3, 30);
+                    tree.insert(1, 10);
+                    tree.insert(3, 31);
+                    tree.right_rotate(root_id);
+                    assert_eq!(tree.delete(&1), Some(10));
+                    assert_eq!(tree.delete(&7), None);
+
+                    assert_eq!(
+                        tree.observer(),
+                        &vec![
+                            (Event::Insert, 5),
+                            (Event::Insert, 3),
+                            (Event::Insert, 1),
+                            (Event::Replace, 3),
+                            (Event::RightRotation, 5),
+                            (Event::Delete, 1),
+                        ]
+                    );
+                }
+
+                #[test]
+                fn test_red_black_events() {
+                    let mut tree =
+                        RedBlackTree::<i32, i32, $bst<i32, Data<i32>, Log>, $node_id>::with_observer(
+                            Vec::new(),
+                        );
+
+                    for key in [1, 2, 3, 2] {
+                        tree.insert(key, key);
+                    }
+                    tree.observer_mut().retain(|(event, _)| *event != Event::Insert);
+
+                    assert_eq!(
+                        tree.observer(),
+                        &vec![
+                            (Event::Recolor { red: false }, 1),
+                            (Event::Recolor { red: false }, 2),
+                            (Event::Recolor { red: true }, 1),
+                            (Event::LeftRotation, 1),
+                            (Event::Replace, 2),
+                        ]
+                    );
+                }
+
+                #[test]
+                fn test_rotation_counter() {
+                    let mut tree = RedBlackTree::<
+                        i32,
+                        i32,
+                        $bst<i32, Data<i32>, RotationCounter>,
+                        $node_id,
+                    >::new();
+
+                    for key in 0..1024 {
+                        tree.insert(key, key);
+                    }
+
+                    assert!(tree.validate().is_ok());
+                    assert!((1000..1024).contains(&tree.observer().rotations));
+                }
+            }
+        )*};
+    }
+
+    observer_tests! {
+        array_backend: ArrayBST, array_bst::NodeId,
+        list_backend: ListBST, list_bst::NodeId<i32, Data<i32>>,
+    }
+
+    #[test]
+    fn test_no_observer_is_free() {
+        assert_eq!(size_of::<NoObserver>(), 0);
+        assert_eq!(
+            size_of::<ListBST<i32, i32>>(),
+            size_of::<Option<std::rc::Rc<()>>>() + size_of::<Vec<std::rc::Rc<()>>>()
+        );
+    }
+}
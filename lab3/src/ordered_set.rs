@@ -0,0 +1,279 @@
+use crate::persistent_red_black_tree::PersistentRedBlackTree;
+use std::cmp::Ordering;
+use std::iter::Peekable;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Union,
+    Intersection,
+    Difference,
+}
+
+pub struct OrderedSet<Key>
+where
+    Key: Ord + Clone,
+{
+    tree: PersistentRedBlackTree<Key, ()>,
+}
+
+impl<Key> Default for OrderedSet<Key>
+where
+    Key: Ord + Clone,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            tree: PersistentRedBlackTree::new(),
+        }
+    }
+}
+
+impl<Key> Clone for OrderedSet<Key>
+where
+    Key: Ord + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            tree: self.tree.clone(),
+        }
+    }
+}
+
+impl<Key> OrderedSet<Key>
+where
+    Key: Ord + Clone,
+{
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    pub fn contains(&self, key: &Key) -> bool {
+        self.tree.contains_key(key)
+    }
+
+    pub fn insert(&mut self, key: Key) -> bool {
+        if self.contains(&key) {
+            return false;
+        }
+
+        self.tree = self.tree.insert(key, ());
+        true
+    }
+
+    pub fn remove(&mut self, key: &Key) -> bool {
+        if !self.contains(key) {
+            return false;
+        }
+
+        self.tree = self.tree.delete(key);
+        true
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Key> {
+        self.tree.iter().map(|(key, _)| key)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.merge(other, Operation::Union)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.merge(other, Operation::Intersection)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.merge(other, Operation::Difference)
+    }
+
+    pub fn split_at(&self, key: &Key) -> (Self, Self) {
+        let (less, greater) = self.tree.split_at(key);
+        (Self { tree: less }, Self { tree: greater })
+    }
+
+    /// Takes logarithmic time when every key of `self` is less than every key of `other`, and
+    /// falls back to a linear `union` otherwise.
+    pub fn join(&self, other: &Self) -> Self {
+        match self.tree.join(&other.tree) {
+            Some(tree) => Self { tree },
+            None => self.union(other),
+        }
+    }
+
+    fn merge(&self, other: &Self, operation: Operation) -> Self {
+        let merged = Merge {
+            left: self.iter().peekable(),
+            right: other.iter().peekable(),
+            operation,
+        };
+
+        Self {
+            tree: PersistentRedBlackTree::from_sorted_iter(merged.map(|key| (key.clone(), ()))),
+        }
+    }
+}
+
+struct Merge<Left, Right>
+where
+    Left: Iterator,
+    Right: Iterator,
+{
+    left: Peekable<Left>,
+    right: Peekable<Right>,
+    operation: Operation,
+}
+
+impl<'a, Key, Left, Right> Iterator for Merge<Left, Right>
+where
+    Key: Ord + 'a,
+    Left: Iterator<Item = &'a Key>,
+    Right: Iterator<Item = &'a Key>,
+{
+    type Item = &'a Key;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let order = match (self.left.peek(), self.right.peek()) {
+                (Some(left), Some(right)) => left.cmp(right),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => return None,
+            };
+
+            match order {
+                Ordering::Less => {
+                    let key = self.left.next();
+                    if self.operation != Operation::Intersection {
+                        return key;
+                    }
+                    self.right.peek()?;
+                }
+                Ordering::Greater => {
+                    let key = self.right.next();
+                    if self.operation == Operation::Union {
+                        return key;
+                    }
+                    self.left.peek()?;
+                }
+                Ordering::Equal => {
+                    self.right.next();
+                    let key = self.left.next();
+                    if self.operation != Operation::Difference {
+                        return key;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<Key> FromIterator<Key> for OrderedSet<Key>
+where
+    Key: Ord + Clone,
+{
+    fn from_iter<I>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = Key>,
+    {
+        let mut keys = keys.into_iter().collect::<Vec<_>>();
+        keys.sort();
+        keys.dedup();
+
+        Self {
+            tree: PersistentRedBlackTree::from_sorted_iter(keys.into_iter().map(|key| (key, ()))),
+        }
+    }
+}
+
+impl<Key> PartialEq for OrderedSet<Key>
+where
+    Key: Ord + Clone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<Key> Eq for OrderedSet<Key> where Key: Ord + Clone {}
+
+impl<Key> std::fmt::Debug for OrderedSet<Key>
+where
+    Key: Ord + Clone + std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::XorShift64;
+    use std::collections::BTreeSet;
+
+    fn random_sets(random: &mut XorShift64) -> (OrderedSet<u64>, BTreeSet<u64>) {
+        let len = random.next_below(200);
+        let keys = (0..len).map(|_| random.next_below(300)).collect::<Vec<_>>();
+
+        (keys.iter().copied().collect(), keys.into_iter().collect())
+    }
+
+    fn assert_matches(set: &OrderedSet<u64>, model: &BTreeSet<u64>) {
+        assert_eq!(set.len(), model.len());
+        assert!(set.iter().eq(model.iter()));
+    }
+
+    #[test]
+    fn test_insert_remove_contains() {
+        let mut set = OrderedSet::new();
+
+        assert!(set.insert(3));
+        assert!(set.insert(1));
+        assert!(!set.insert(3));
+        assert!(set.contains(&1));
+        assert!(!set.contains(&2));
+        assert!(set.remove(&1));
+        assert!(!set.remove(&1));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![&3]);
+        assert_eq!(format!("{:?}", set), "{3}");
+    }
+
+    #[test]
+    fn test_set_algebra_against_model() {
+        let mut random = XorShift64::new(0x5E7);
+
+        for _ in 0..200 {
+            let (left, left_model) = random_sets(&mut random);
+            let (right, right_model) = random_sets(&mut random);
+
+            assert_matches(&left.union(&right), &(&left_model | &right_model));
+            assert_matches(&left.intersection(&right), &(&left_model & &right_model));
+            assert_matches(&left.difference(&right), &(&left_model - &right_model));
+            assert_matches(&right.difference(&left), &(&right_model - &left_model));
+        }
+    }
+
+    #[test]
+    fn test_split_at_and_join() {
+        let mut random = XorShift64::new(0x5917);
+
+        for _ in 0..200 {
+            let (set, model) = random_sets(&mut random);
+            let pivot = random.next_below(320);
+
+            let (less, greater) = set.split_at(&pivot);
+            assert_matches(&less, &model.range(..pivot).copied().collect());
+            assert_matches(&greater, &model.range(pivot..).copied().collect());
+            assert_eq!(less.join(&greater), set);
+            assert_eq!(greater.join(&less), set);
+            assert_eq!(set.join(&less), set);
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use crate::traits::{NodeIdentifiableTree, ParentifiedTree};
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+
+pub(crate) fn get_entry<Key, Value, NodeId, ImplTree>(
+    tree: &ImplTree,
+    node_id: NodeId,
+) -> Option<(Key, Value)>
+where
+    Key: Clone,
+    Value: Clone,
+    NodeId: Clone,
+    ImplTree: NodeIdentifiableTree<Key, Value, NodeId>,
+{
+    let key = tree.get_key_by_id(node_id.clone())?.clone();
+    let value = tree.get_by_id(node_id)?.clone();
+    Some((key, value))
+}
+
+pub(crate) fn collect_sorted<Key, Value, I>(entries: I) -> Vec<(Key, Value)>
+where
+    Key: Ord,
+    I: IntoIterator<Item = (Key, Value)>,
+{
+    let entries = entries.into_iter().collect::<Vec<_>>();
+    assert!(
+        entries.windows(2).all(|pair| pair[0].0 < pair[1].0),
+        "Keys must be strictly increasing"
+    );
+
+    entries
+}
+
+pub(crate) fn get_min_id<Key, Value, NodeId, ImplTree>(
+    tree: &ImplTree,
+    mut node_id: NodeId,
+) -> NodeId
+where
+    NodeId: Clone,
+    ImplTree: NodeIdentifiableTree<Key, Value, NodeId>,
+{
+    while let Some(left_son_id) = tree.get_left_son_id(node_id.clone()) {
+        node_id = left_son_id;
+    }
+
+    node_id
+}
+
+pub(crate) fn get_max_id<Key, Value, NodeId, ImplTree>(
+    tree: &ImplTree,
+    mut node_id: NodeId,
+) -> NodeId
+where
+    NodeId: Clone,
+    ImplTree: NodeIdentifiableTree<Key, Value, NodeId>,
+{
+    while let Some(right_son_id) = tree.get_right_son_id(node_id.clone()) {
+        node_id = right_son_id;
+    }
+
+    node_id
+}
+
+pub(crate) fn get_floor_id<Key, Value, NodeId, ImplTree>(
+    tree: &ImplTree,
+    key: &Key,
+    inclusive: bool,
+) -> Option<NodeId>
+where
+    Key: Ord,
+    NodeId: Clone,
+    ImplTree: NodeIdentifiableTree<Key, Value, NodeId>,
+{
+    let mut found_id = None;
+    let mut maybe_node_id = tree.get_root_id();
+
+    while let Some(node_id) = maybe_node_id {
+        let node_key = tree.get_key_by_id(node_id.clone()).expect("Node not found");
+
+        match node_key.cmp(key) {
+            Ordering::Equal if inclusive => return Some(node_id),
+            Ordering::Less => {
+                maybe_node_id = tree.get_right_son_id(node_id.clone());
+                found_id = Some(node_id);
+            }
+            _ => maybe_node_id = tree.get_left_son_id(node_id),
+        }
+    }
+
+    found_id
+}
+
+pub(crate) fn get_ceiling_id<Key, Value, NodeId, ImplTree>(
+    tree: &ImplTree,
+    key: &Key,
+    inclusive: bool,
+) -> Option<NodeId>
+where
+    Key: Ord,
+    NodeId: Clone,
+    ImplTree: NodeIdentifiableTree<Key, Value, NodeId>,
+{
+    let mut found_id = None;
+    let mut maybe_node_id = tree.get_root_id();
+
+    while let Some(node_id) = maybe_node_id {
+        let node_key = tree.get_key_by_id(node_id.clone()).expect("Node not found");
+
+        match node_key.cmp(key) {
+            Ordering::Equal if inclusive => return Some(node_id),
+            Ordering::Greater => {
+                maybe_node_id = tree.get_left_son_id(node_id.clone());
+                found_id = Some(node_id);
+            }
+            _ => maybe_node_id = tree.get_right_son_id(node_id),
+        }
+    }
+
+    found_id
+}
+
+pub(crate) fn get_next_id<Key, Value, NodeId, ImplTree>(
+    tree: &ImplTree,
+    mut node_id: NodeId,
+) -> Option<NodeId>
+where
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Value, NodeId>,
+{
+    if let Some(right_son_id) = tree.get_right_son_id(node_id.clone()) {
+        return Some(get_min_id(tree, right_son_id));
+    }
+
+    while tree.is_right_son(node_id.clone()) {
+        node_id = tree.get_parent_id(node_id).expect("Parent not found");
+    }
+
+    tree.get_parent_id(node_id)
+}
+
+pub(crate) fn get_prev_id<Key, Value, NodeId, ImplTree>(
+    tree: &ImplTree,
+    mut node_id: NodeId,
+) -> Option<NodeId>
+where
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Value, NodeId>,
+{
+    if let Some(left_son_id) = tree.get_left_son_id(node_id.clone()) {
+        return Some(get_max_id(tree, left_son_id));
+    }
+
+    while tree.is_left_son(node_id.clone()) {
+        node_id = tree.get_parent_id(node_id).expect("Parent not found");
+    }
+
+    tree.get_parent_id(node_id)
+}
+
+pub(crate) fn get_rank<Key, Value, NodeId, ImplTree>(tree: &ImplTree, key: &Key) -> usize
+where
+    Key: Ord,
+    NodeId: Clone,
+    ImplTree: NodeIdentifiableTree<Key, Value, NodeId>,
+{
+    let mut rank = 0;
+    let mut maybe_node_id = tree.get_root_id();
+
+    while let Some(node_id) = maybe_node_id {
+        let left_son_id = tree.get_left_son_id(node_id.clone());
+
+        if key <= tree.get_key_by_id(node_id.clone()).expect("Node not found") {
+            maybe_node_id = left_son_id;
+        } else {
+            rank += 1 + left_son_id.map_or(0, |left_son_id| tree.get_subtree_size(left_son_id));
+            maybe_node_id = tree.get_right_son_id(node_id);
+        }
+    }
+
+    rank
+}
+
+pub(crate) fn get_select_id<Key, Value, NodeId, ImplTree>(
+    tree: &ImplTree,
+    mut rank: usize,
+) -> Option<NodeId>
+where
+    NodeId: Clone,
+    ImplTree: NodeIdentifiableTree<Key, Value, NodeId>,
+{
+    let mut node_id = tree.get_root_id()?;
+
+    loop {
+        let left_son_id = tree.get_left_son_id(node_id.clone());
+        let left_size = left_son_id
+            .clone()
+            .map_or(0, |left_son_id| tree.get_subtree_size(left_son_id));
+
+        node_id = match rank.cmp(&left_size) {
+            Ordering::Less => left_son_id?,
+            Ordering::Equal => return Some(node_id),
+            Ordering::Greater => {
+                rank -= left_size + 1;
+                tree.get_right_son_id(node_id)?
+            }
+        };
+    }
+}
+
+pub(crate) struct RangeIter<'a, Key, Value, NodeId, ImplTree> {
+    tree: &'a ImplTree,
+    next_id: Option<NodeId>,
+    end: Bound<Key>,
+    _phantom_value: PhantomData<Value>,
+}
+
+impl<'a, Key, Value, NodeId, ImplTree> RangeIter<'a, Key, Value, NodeId, ImplTree>
+where
+    Key: Ord + Clone,
+    NodeId: Clone,
+    ImplTree: NodeIdentifiableTree<Key, Value, NodeId>,
+{
+    pub(crate) fn new<R>(tree: &'a ImplTree, range: R) -> Self
+    where
+        R: RangeBounds<Key>,
+    {
+        let next_id = match range.start_bound() {
+            Bound::Included(start) => get_ceiling_id(tree, start, true),
+            Bound::Excluded(start) => get_ceiling_id(tree, start, false),
+            Bound::Unbounded => tree
+                .get_root_id()
+                .map(|root_id| get_min_id(tree, root_id)),
+        };
+
+        Self {
+            tree,
+            next_id,
+            end: range.end_bound().cloned(),
+            _phantom_value: PhantomData,
+        }
+    }
+}
+
+impl<Key, Value, NodeId, ImplTree> Iterator for RangeIter<'_, Key, Value, NodeId, ImplTree>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Value, NodeId>,
+{
+    type Item = (Key, Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node_id = self.next_id.take()?;
+        let (key, value) = get_entry(self.tree, node_id.clone())?;
+
+        let in_range = match &self.end {
+            Bound::Included(end) => key <= *end,
+            Bound::Excluded(end) => key < *end,
+            Bound::Unbounded => true,
+        };
+
+        if !in_range {
+            return None;
+        }
+
+        self.next_id = get_next_id(self.tree, node_id);
+        Some((key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array_bst::ArrayBST;
+    use crate::b_plus_tree::BPlusTree;
+    use crate::b_tree::BTree;
+    use crate::list_bst::ListBST;
+    use crate::test_utils::XorShift64;
+    use crate::traits::{OrderedTree, Tree};
+    use crate::{ArrayRedBlackTree, ListRedBlackTree};
+    use std::collections::BTreeMap;
+    use std::ops::Bound;
+
+    fn check_against_model<T>(tree: &T, model: &BTreeMap<i32, i32>)
+    where
+        T: OrderedTree<i32, i32>,
+    {
+        let entry = |(key, val): (&i32, &i32)| (*key, *val);
+
+        assert_eq!(tree.min(), model.first_key_value().map(entry));
+        assert_eq!(tree.max(), model.last_key_value().map(entry));
+
+        for key in -5..105 {
+            assert_eq!(tree.floor(&key), model.range(..=key).next_back().map(entry));
+            assert_eq!(tree.ceiling(&key), model.range(key..).next().map(entry));
+            assert_eq!(tree.predecessor(&key), model.range(..key).next_back().map(entry));
+            assert_eq!(
+                tree.successor(&key),
+                model.range((Bound::Excluded(key), Bound::Unbounded)).next().map(entry)
+            );
+            assert_eq!(tree.rank(&key), model.range(..key).count());
+        }
+
+        for rank in 0..model.len() + 2 {
+            assert_eq!(tree.select(rank), model.iter().nth(rank).map(entry));
+        }
+
+        let collect = |iter: &mut dyn Iterator<Item = (&i32, &i32)>| {
+            iter.map(entry).collect::<Vec<_>>()
+        };
+
+        assert_eq!(tree.range(..).collect::<Vec<_>>(), collect(&mut model.iter()));
+        assert_eq!(tree.range(20..60).collect::<Vec<_>>(), collect(&mut model.range(20..60)));
+        assert_eq!(tree.range(20..=60).collect::<Vec<_>>(), collect(&mut model.range(20..=60)));
+        assert_eq!(tree.range(..30).collect::<Vec<_>>(), collect(&mut model.range(..30)));
+        assert_eq!(tree.range(70..).collect::<Vec<_>>(), collect(&mut model.range(70..)));
+        assert_eq!(
+            tree.range((Bound::Excluded(40), Bound::Included(80)))
+                .collect::<Vec<_>>(),
+            collect(&mut model.range((Bound::Excluded(40), Bound::Included(80))))
+        );
+        assert_eq!(tree.range(50..50).count(), 0);
+    }
+
+    type SmallBTree<Key, Value> = BTree<Key, Value, 3>;
+    type SmallBPlusTree<Key, Value> = BPlusTree<Key, Value, 3>;
+
+    macro_rules! ordered_tree_tests {
+        ($($backend:ident: $tree:ident,)*) => {$(
+            #[test]
+            fn $backend() {
+                let mut random = XorShift64::new(0x0DE5);
+                let mut tree = $tree::<i32, i32>::default();
+                let mut model = BTreeMap::new();
+
+                check_against_model(&tree, &model);
+
+                for step in 0..600 {
+                    let key = random.next_below(100) as i32;
+
+                    if random.next_below(3) == 0 {
+                        assert_eq!(tree.delete(&key), model.remove(&key));
+                    } else {
+                        tree.insert(key, step);
+                        model.insert(key, step);
+                    }
+
+                    if step % 50 == 0 {
+                        check_against_model(&tree, &model);
+                    }
+                }
+
+                check_against_model(&tree, &model);
+            }
+        )*};
+    }
+
+    ordered_tree_tests! {
+        array_bst: ArrayBST,
+        list_bst: ListBST,
+        array_red_black_tree: ArrayRedBlackTree,
+        list_red_black_tree: ListRedBlackTree,
+        b_tree: SmallBTree,
+        b_plus_tree: SmallBPlusTree,
+        wide_b_tree: BTree,
+        wide_b_plus_tree: BPlusTree,
+    }
+}
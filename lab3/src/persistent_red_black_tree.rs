@@ -0,0 +1,860 @@
+use crate::ordered_tree;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+type Color = bool;
+
+const RED: Color = true;
+const BLACK: Color = false;
+
+type Link<Key, Value> = Option<Arc<Node<Key, Value>>>;
+
+#[derive(Clone)]
+struct Node<Key, Value> {
+    key: Key,
+    value: Value,
+    color: Color,
+    left: Link<Key, Value>,
+    right: Link<Key, Value>,
+    size: usize,
+}
+
+impl<Key, Value> Node<Key, Value> {
+    fn new(key: Key, value: Value) -> Self {
+        Self {
+            key,
+            value,
+            color: RED,
+            left: None,
+            right: None,
+            size: 1,
+        }
+    }
+
+    fn update_size(&mut self) {
+        self.size = 1 + get_size(&self.left) + get_size(&self.right);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Violation<Key> {
+    RedRoot,
+    RightLeaningRed(Key),
+    RedSonOfRedNode(Key),
+    BlackHeightMismatch(Key),
+    KeysOutOfOrder(Key),
+    WrongSize(Key),
+}
+
+impl<Key> std::fmt::Display for Violation<Key>
+where
+    Key: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::RedRoot => write!(f, "root is red"),
+            Self::RightLeaningRed(key) => write!(f, "node {:?} has a red right son", key),
+            Self::RedSonOfRedNode(key) => write!(f, "red node {:?} has a red parent", key),
+            Self::BlackHeightMismatch(key) => {
+                write!(f, "subtrees of {:?} have different black heights", key)
+            }
+            Self::KeysOutOfOrder(key) => write!(f, "key {:?} breaks the search tree order", key),
+            Self::WrongSize(key) => write!(f, "node {:?} stores a wrong subtree size", key),
+        }
+    }
+}
+
+fn get_size<Key, Value>(link: &Link<Key, Value>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+fn is_red<Key, Value>(link: &Link<Key, Value>) -> bool {
+    link.as_ref().is_some_and(|node| node.color == RED)
+}
+
+fn is_left_son_red<Key, Value>(link: &Link<Key, Value>) -> bool {
+    link.as_ref().is_some_and(|node| is_red(&node.left))
+}
+
+fn take_son<Key, Value>(link: &mut Link<Key, Value>) -> Node<Key, Value>
+where
+    Key: Clone,
+    Value: Clone,
+{
+    Arc::unwrap_or_clone(link.take().expect("Son not found"))
+}
+
+fn rotate_left<Key, Value>(mut node: Node<Key, Value>) -> Node<Key, Value>
+where
+    Key: Clone,
+    Value: Clone,
+{
+    let mut son = take_son(&mut node.right);
+    node.right = son.left.take();
+    son.color = node.color;
+    node.color = RED;
+    node.update_size();
+    son.left = Some(Arc::new(node));
+    son.update_size();
+    son
+}
+
+fn rotate_right<Key, Value>(mut node: Node<Key, Value>) -> Node<Key, Value>
+where
+    Key: Clone,
+    Value: Clone,
+{
+    let mut son = take_son(&mut node.left);
+    node.left = son.right.take();
+    son.color = node.color;
+    node.color = RED;
+    node.update_size();
+    son.right = Some(Arc::new(node));
+    son.update_size();
+    son
+}
+
+fn flip_colors<Key, Value>(node: &mut Node<Key, Value>)
+where
+    Key: Clone,
+    Value: Clone,
+{
+    node.color = !node.color;
+
+    for link in [&mut node.left, &mut node.right] {
+        if link.is_some() {
+            let mut son = take_son(link);
+            son.color = !son.color;
+            *link = Some(Arc::new(son));
+        }
+    }
+}
+
+fn balance<Key, Value>(mut node: Node<Key, Value>) -> Node<Key, Value>
+where
+    Key: Clone,
+    Value: Clone,
+{
+    if is_red(&node.right) && !is_red(&node.left) {
+        node = rotate_left(node);
+    }
+
+    if is_red(&node.left) && is_left_son_red(&node.left) {
+        node = rotate_right(node);
+    }
+
+    if is_red(&node.left) && is_red(&node.right) {
+        flip_colors(&mut node);
+    }
+
+    node.update_size();
+    node
+}
+
+fn move_red_left<Key, Value>(mut node: Node<Key, Value>) -> Node<Key, Value>
+where
+    Key: Clone,
+    Value: Clone,
+{
+    flip_colors(&mut node);
+
+    if is_left_son_red(&node.right) {
+        let right_son = rotate_right(take_son(&mut node.right));
+        node.right = Some(Arc::new(right_son));
+        node = rotate_left(node);
+        flip_colors(&mut node);
+    }
+
+    node
+}
+
+fn move_red_right<Key, Value>(mut node: Node<Key, Value>) -> Node<Key, Value>
+where
+    Key: Clone,
+    Value: Clone,
+{
+    flip_colors(&mut node);
+
+    if is_left_son_red(&node.left) {
+        node = rotate_right(node);
+        flip_colors(&mut node);
+    }
+
+    node
+}
+
+fn insert_into<Key, Value>(link: &Link<Key, Value>, key: Key, value: Value) -> Node<Key, Value>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    let Some(node) = link else {
+        return Node::new(key, value);
+    };
+
+    let mut node = Node::clone(node);
+    match key.cmp(&node.key) {
+        Ordering::Less => node.left = Some(Arc::new(insert_into(&node.left, key, value))),
+        Ordering::Greater => node.right = Some(Arc::new(insert_into(&node.right, key, value))),
+        Ordering::Equal => node.value = value,
+    }
+
+    balance(node)
+}
+
+fn delete_min<Key, Value>(mut node: Node<Key, Value>) -> (Option<Node<Key, Value>>, Key, Value)
+where
+    Key: Clone,
+    Value: Clone,
+{
+    if node.left.is_none() {
+        return (None, node.key, node.value);
+    }
+
+    if !is_red(&node.left) && !is_left_son_red(&node.left) {
+        node = move_red_left(node);
+    }
+
+    let (left_son, key, value) = delete_min(take_son(&mut node.left));
+    node.left = left_son.map(Arc::new);
+
+    (Some(balance(node)), key, value)
+}
+
+fn delete_from<Key, Value>(mut node: Node<Key, Value>, key: &Key) -> Option<Node<Key, Value>>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    if *key < node.key {
+        if !is_red(&node.left) && !is_left_son_red(&node.left) {
+            node = move_red_left(node);
+        }
+
+        node.left = delete_from(take_son(&mut node.left), key).map(Arc::new);
+    } else {
+        if is_red(&node.left) {
+            node = rotate_right(node);
+        }
+
+        if *key == node.key && node.right.is_none() {
+            return None;
+        }
+
+        if !is_red(&node.right) && !is_left_son_red(&node.right) {
+            node = move_red_right(node);
+        }
+
+        if *key == node.key {
+            let (right_son, min_key, min_value) = delete_min(take_son(&mut node.right));
+            node.key = min_key;
+            node.value = min_value;
+            node.right = right_son.map(Arc::new);
+        } else {
+            node.right = delete_from(take_son(&mut node.right), key).map(Arc::new);
+        }
+    }
+
+    Some(balance(node))
+}
+
+type Part<Key, Value> = (Link<Key, Value>, usize);
+type Split<Key, Value> = (Part<Key, Value>, Option<(Key, Value)>, Part<Key, Value>);
+
+fn get_black_height<Key, Value>(mut link: &Link<Key, Value>) -> usize {
+    let mut black_height = 0;
+    while let Some(node) = link {
+        black_height += usize::from(node.color == BLACK);
+        link = &node.left;
+    }
+
+    black_height
+}
+
+fn blacken<Key, Value>((link, black_height): Part<Key, Value>) -> Part<Key, Value>
+where
+    Key: Clone,
+    Value: Clone,
+{
+    match link {
+        Some(node) if node.color == RED => {
+            let mut node = Arc::unwrap_or_clone(node);
+            node.color = BLACK;
+            (Some(Arc::new(node)), black_height + 1)
+        }
+        link => (link, black_height),
+    }
+}
+
+fn join_right<Key, Value>(
+    (left, left_black_height): Part<Key, Value>,
+    key: Key,
+    value: Value,
+    (right, right_black_height): Part<Key, Value>,
+) -> Node<Key, Value>
+where
+    Key: Clone,
+    Value: Clone,
+{
+    if left_black_height == right_black_height && !is_red(&left) {
+        let mut node = Node::new(key, value);
+        node.left = left;
+        node.right = right;
+        node.update_size();
+        return node;
+    }
+
+    let mut node = Arc::unwrap_or_clone(left.expect("Son not found"));
+    let son_black_height = left_black_height - usize::from(node.color == BLACK);
+    let son = join_right(
+        (node.right.take(), son_black_height),
+        key,
+        value,
+        (right, right_black_height),
+    );
+    node.right = Some(Arc::new(son));
+
+    balance(node)
+}
+
+fn join_left<Key, Value>(
+    (left, left_black_height): Part<Key, Value>,
+    key: Key,
+    value: Value,
+    (right, right_black_height): Part<Key, Value>,
+) -> Node<Key, Value>
+where
+    Key: Clone,
+    Value: Clone,
+{
+    if left_black_height == right_black_height && !is_red(&right) {
+        let mut node = Node::new(key, value);
+        node.left = left;
+        node.right = right;
+        node.update_size();
+        return node;
+    }
+
+    let mut node = Arc::unwrap_or_clone(right.expect("Son not found"));
+    let son_black_height = right_black_height - usize::from(node.color == BLACK);
+    let son = join_left(
+        (left, left_black_height),
+        key,
+        value,
+        (node.left.take(), son_black_height),
+    );
+    node.left = Some(Arc::new(son));
+
+    balance(node)
+}
+
+fn join<Key, Value>(
+    left: Part<Key, Value>,
+    key: Key,
+    value: Value,
+    right: Part<Key, Value>,
+) -> Part<Key, Value>
+where
+    Key: Clone,
+    Value: Clone,
+{
+    let left = blacken(left);
+    let right = blacken(right);
+    let black_height = left.1.max(right.1);
+
+    let root = if left.1 >= right.1 {
+        join_right(left, key, value, right)
+    } else {
+        join_left(left, key, value, right)
+    };
+
+    blacken((Some(Arc::new(root)), black_height))
+}
+
+fn split<Key, Value>(
+    link: &Link<Key, Value>,
+    black_height: usize,
+    key: &Key,
+) -> Split<Key, Value>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    let Some(node) = link else {
+        return ((None, 0), None, (None, 0));
+    };
+
+    let son_black_height = black_height - usize::from(node.color == BLACK);
+    let left_son = (node.left.clone(), son_black_height);
+    let right_son = (node.right.clone(), son_black_height);
+    let (key_copy, value_copy) = (node.key.clone(), node.value.clone());
+
+    match key.cmp(&node.key) {
+        Ordering::Less => {
+            let (less, found, greater) = split(&node.left, son_black_height, key);
+            (less, found, join(greater, key_copy, value_copy, right_son))
+        }
+        Ordering::Greater => {
+            let (less, found, greater) = split(&node.right, son_black_height, key);
+            (join(left_son, key_copy, value_copy, less), found, greater)
+        }
+        Ordering::Equal => (left_son, Some((key_copy, value_copy)), right_son),
+    }
+}
+
+fn build<Key, Value, I>(entries: &mut I, len: usize) -> Part<Key, Value>
+where
+    Key: Clone,
+    Value: Clone,
+    I: Iterator<Item = (Key, Value)>,
+{
+    if len == 0 {
+        return (None, 0);
+    }
+
+    let left = build(entries, len / 2);
+    let (key, value) = entries.next().expect("Entry not found");
+    let right = build(entries, len - len / 2 - 1);
+
+    join(left, key, value, right)
+}
+
+pub struct PersistentRedBlackTree<Key, Value>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    root: Link<Key, Value>,
+}
+
+impl<Key, Value> Default for PersistentRedBlackTree<Key, Value>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self { root: None }
+    }
+}
+
+impl<Key, Value> Clone for PersistentRedBlackTree<Key, Value>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+        }
+    }
+}
+
+impl<Key, Value> PersistentRedBlackTree<Key, Value>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn len(&self) -> usize {
+        get_size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&Value> {
+        let mut maybe_node = self.root.as_deref();
+
+        while let Some(node) = maybe_node {
+            maybe_node = match key.cmp(&node.key) {
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref(),
+                Ordering::Equal => return Some(&node.value),
+            };
+        }
+
+        None
+    }
+
+    pub fn contains_key(&self, key: &Key) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn insert(&self, key: Key, value: Value) -> Self {
+        let mut root = insert_into(&self.root, key, value);
+        root.color = BLACK;
+
+        Self {
+            root: Some(Arc::new(root)),
+        }
+    }
+
+    pub fn delete(&self, key: &Key) -> Self {
+        let Some(root) = self.root.as_deref().filter(|_| self.contains_key(key)) else {
+            return self.clone();
+        };
+
+        let mut root = Node::clone(root);
+        if !is_red(&root.left) && !is_red(&root.right) {
+            root.color = RED;
+        }
+
+        Self {
+            root: delete_from(root, key).map(|mut root| {
+                root.color = BLACK;
+                Arc::new(root)
+            }),
+        }
+    }
+
+    pub fn from_sorted_iter<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (Key, Value)>,
+    {
+        let entries = ordered_tree::collect_sorted(entries);
+
+        let len = entries.len();
+        Self {
+            root: build(&mut entries.into_iter(), len).0,
+        }
+    }
+
+    pub fn split_at(&self, key: &Key) -> (Self, Self) {
+        let (less, found, greater) = split(&self.root, get_black_height(&self.root), key);
+        let greater = match found {
+            Some((key, value)) => join((None, 0), key, value, greater),
+            None => greater,
+        };
+
+        (
+            Self {
+                root: blacken(less).0,
+            },
+            Self {
+                root: blacken(greater).0,
+            },
+        )
+    }
+
+    /// Concatenates two trees in logarithmic time. Every key of `self` must be less than every
+    /// key of `other`; returns `None` otherwise.
+    pub fn join(&self, other: &Self) -> Option<Self> {
+        let Some((min_key, min_value)) = other.iter().next() else {
+            return Some(self.clone());
+        };
+        if self.max_key().is_some_and(|max_key| max_key >= min_key) {
+            return None;
+        }
+
+        let rest = other.delete(min_key);
+        Some(Self {
+            root: join(
+                (self.root.clone(), get_black_height(&self.root)),
+                min_key.clone(),
+                min_value.clone(),
+                (rest.root.clone(), get_black_height(&rest.root)),
+            )
+            .0,
+        })
+    }
+
+    fn max_key(&self) -> Option<&Key> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right_son) = node.right.as_deref() {
+            node = right_son;
+        }
+
+        Some(&node.key)
+    }
+
+    pub fn iter(&self) -> Iter<'_, Key, Value> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left_spine(self.root.as_deref());
+        iter
+    }
+
+    pub fn validate(&self) -> Result<usize, Violation<Key>> {
+        if is_red(&self.root) {
+            return Err(Violation::RedRoot);
+        }
+
+        Self::validate_subtree(&self.root, None, None)
+    }
+
+    fn validate_subtree(
+        link: &Link<Key, Value>,
+        lower: Option<&Key>,
+        upper: Option<&Key>,
+    ) -> Result<usize, Violation<Key>> {
+        let Some(node) = link else {
+            return Ok(0);
+        };
+
+        if lower.is_some_and(|lower| node.key <= *lower)
+            || upper.is_some_and(|upper| node.key >= *upper)
+        {
+            return Err(Violation::KeysOutOfOrder(node.key.clone()));
+        }
+
+        if is_red(&node.right) {
+            return Err(Violation::RightLeaningRed(node.key.clone()));
+        }
+
+        if node.color == RED && is_red(&node.left) {
+            return Err(Violation::RedSonOfRedNode(node.key.clone()));
+        }
+
+        if node.size != 1 + get_size(&node.left) + get_size(&node.right) {
+            return Err(Violation::WrongSize(node.key.clone()));
+        }
+
+        let left_black_height = Self::validate_subtree(&node.left, lower, Some(&node.key))?;
+        let right_black_height = Self::validate_subtree(&node.right, Some(&node.key), upper)?;
+        if left_black_height != right_black_height {
+            return Err(Violation::BlackHeightMismatch(node.key.clone()));
+        }
+
+        Ok(left_black_height + usize::from(node.color == BLACK))
+    }
+}
+
+pub struct Iter<'a, Key, Value> {
+    stack: Vec<&'a Node<Key, Value>>,
+}
+
+impl<'a, Key, Value> Iter<'a, Key, Value> {
+    fn push_left_spine(&mut self, mut maybe_node: Option<&'a Node<Key, Value>>) {
+        while let Some(node) = maybe_node {
+            self.stack.push(node);
+            maybe_node = node.left.as_deref();
+        }
+    }
+}
+
+impl<'a, Key, Value> Iterator for Iter<'a, Key, Value> {
+    type Item = (&'a Key, &'a Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left_spine(node.right.as_deref());
+        Some((&node.key, &node.value))
+    }
+}
+
+impl<'a, Key, Value> IntoIterator for &'a PersistentRedBlackTree<Key, Value>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+{
+    type Item = (&'a Key, &'a Value);
+    type IntoIter = Iter<'a, Key, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::XorShift64;
+    use std::collections::{BTreeMap, HashSet};
+
+    fn collect_nodes<Key, Value>(
+        link: &Link<Key, Value>,
+        nodes: &mut HashSet<*const Node<Key, Value>>,
+    ) {
+        if let Some(node) = link
+            && nodes.insert(Arc::as_ptr(node))
+        {
+            collect_nodes(&node.left, nodes);
+            collect_nodes(&node.right, nodes);
+        }
+    }
+
+    fn count_new_nodes(
+        old: &PersistentRedBlackTree<u64, u64>,
+        new: &PersistentRedBlackTree<u64, u64>,
+    ) -> usize {
+        let mut old_nodes = HashSet::new();
+        let mut new_nodes = HashSet::new();
+        collect_nodes(&old.root, &mut old_nodes);
+        collect_nodes(&new.root, &mut new_nodes);
+
+        new_nodes.difference(&old_nodes).count()
+    }
+
+    #[test]
+    fn test_snapshots() {
+        let empty = PersistentRedBlackTree::<i32, &str>::new();
+        let first = empty.insert(2, "two").insert(1, "one");
+        let second = first.insert(3, "three").insert(2, "TWO");
+        let third = second.delete(&1).delete(&7);
+
+        assert!(empty.is_empty());
+        assert_eq!(
+            first.iter().collect::<Vec<_>>(),
+            vec![(&1, &"one"), (&2, &"two")]
+        );
+        assert_eq!(
+            second.iter().collect::<Vec<_>>(),
+            vec![(&1, &"one"), (&2, &"TWO"), (&3, &"three")]
+        );
+        assert_eq!(
+            third.iter().collect::<Vec<_>>(),
+            vec![(&2, &"TWO"), (&3, &"three")]
+        );
+        assert_eq!(first.get(&2), Some(&"two"));
+        assert_eq!(third.get(&1), None);
+        assert_eq!(third.len(), 2);
+
+        let unchanged = third.delete(&42);
+        assert!(Arc::ptr_eq(
+            unchanged.root.as_ref().unwrap(),
+            third.root.as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_random_versions_against_model() {
+        for seed in 1..=4 {
+            let mut random = XorShift64::new(seed);
+            let mut tree = PersistentRedBlackTree::new();
+            let mut model = BTreeMap::new();
+            let mut versions = Vec::new();
+
+            for step in 0..2000 {
+                let key = random.next_below(300);
+                if random.next_below(3) > 0 {
+                    tree = tree.insert(key, step);
+                    model.insert(key, step);
+                } else {
+                    assert_eq!(tree.get(&key).copied(), model.get(&key).copied());
+                    tree = tree.delete(&key);
+                    model.remove(&key);
+                }
+
+                assert!(tree.validate().is_ok(), "seed {}, step {}", seed, step);
+                if step % 50 == 0 {
+                    versions.push((tree.clone(), model.clone()));
+                }
+            }
+
+            for (version, snapshot) in &versions {
+                assert!(version.validate().is_ok());
+                assert_eq!(version.len(), snapshot.len());
+                assert!(
+                    version
+                        .iter()
+                        .map(|(key, value)| (*key, *value))
+                        .eq(snapshot.clone())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_path_copying() {
+        let mut tree = PersistentRedBlackTree::new();
+        for key in 0..4096 {
+            tree = tree.insert(key * 2, key);
+        }
+
+        let black_height = tree.validate().unwrap();
+        let max_copied = 3 * (2 * black_height + 1);
+
+        let inserted = tree.insert(4097, 0);
+        assert!(count_new_nodes(&tree, &inserted) <= max_copied);
+
+        let replaced = tree.insert(4096, 0);
+        assert!(count_new_nodes(&tree, &replaced) <= max_copied);
+
+        let deleted = tree.delete(&2048);
+        assert!(count_new_nodes(&tree, &deleted) <= max_copied);
+
+        let (less, greater) = tree.split_at(&4096);
+        let split_copied = count_new_nodes(&tree, &less) + count_new_nodes(&tree, &greater);
+        assert!(split_copied <= max_copied);
+
+        assert_eq!(tree.len(), 4096);
+        assert_eq!(deleted.len(), 4095);
+        assert_eq!(tree.get(&2048), Some(&1024));
+    }
+
+    #[test]
+    fn test_from_sorted_iter() {
+        for len in 0..200 {
+            let entries = (0..len).map(|key| (key, key * 2));
+            let tree = PersistentRedBlackTree::from_sorted_iter(entries.clone());
+
+            assert!(tree.validate().is_ok(), "len {}", len);
+            assert_eq!(tree.len(), len as usize);
+            assert!(tree.iter().map(|(key, value)| (*key, *value)).eq(entries));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Keys must be strictly increasing")]
+    fn test_from_unsorted_iter() {
+        PersistentRedBlackTree::from_sorted_iter([(2, ()), (1, ())]);
+    }
+
+    #[test]
+    fn test_split_and_join() {
+        let mut random = XorShift64::new(0x5EED);
+
+        for _ in 0..200 {
+            let len = random.next_below(300);
+            let mut tree = PersistentRedBlackTree::new();
+            for _ in 0..len {
+                let key = random.next_below(1000);
+                tree = tree.insert(key, key);
+            }
+            let pivot = random.next_below(1100);
+
+            let (less, greater) = tree.split_at(&pivot);
+            assert!(less.validate().is_ok());
+            assert!(greater.validate().is_ok());
+            assert!(less.iter().all(|(key, _)| *key < pivot));
+            assert!(greater.iter().all(|(key, _)| *key >= pivot));
+            assert_eq!(less.len() + greater.len(), tree.len());
+
+            let joined = less.join(&greater).unwrap();
+            assert!(joined.validate().is_ok());
+            assert!(joined.iter().eq(tree.iter()));
+        }
+    }
+
+    #[test]
+    fn test_join_trees_of_different_heights() {
+        let small = PersistentRedBlackTree::from_sorted_iter((0..3).map(|key| (key, ())));
+        let large =
+            PersistentRedBlackTree::from_sorted_iter((10..1000).map(|key| (key, ())));
+
+        for (left, right) in [(&small, &large), (&small, &PersistentRedBlackTree::new())] {
+            let joined = left.join(right).unwrap();
+            assert!(joined.validate().is_ok());
+            assert_eq!(joined.len(), left.len() + right.len());
+        }
+
+        let low =
+            PersistentRedBlackTree::from_sorted_iter((-1000..-3).map(|key| (key, ())));
+        let joined = low.join(&small).unwrap();
+        assert!(joined.validate().is_ok());
+        assert!(joined.iter().map(|(key, _)| *key).eq((-1000..-3).chain(0..3)));
+    }
+
+    #[test]
+    fn test_join_overlapping_trees() {
+        let tree = PersistentRedBlackTree::from_sorted_iter([(1, ()), (5, ())]);
+        let touching = PersistentRedBlackTree::from_sorted_iter([(5, ()), (9, ())]);
+        assert!(tree.join(&tree).is_none());
+        assert!(tree.join(&touching).is_none());
+        assert!(touching.join(&tree).is_none());
+    }
+}
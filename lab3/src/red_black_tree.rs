@@ -1,5 +1,18 @@
-use crate::traits::{NodeIdentifiableTree, ParentifiedTree, RotatableTree, Tree};
+use crate::export::{self, LoadError};
+use crate::guards::{GuardRef, GuardRefMut, Guards};
+use crate::observer::Event;
+use crate::traits::{
+    BulkLoadableTree, NodeIdentifiableTree, ObservableTree, OrderedTree, ParentifiedTree,
+    RotatableTree, TraversableTree, Tree,
+};
+use crate::traversal::{
+    DoubleEndedLendingIterator, DoubleEndedTraversalOrder, InOrder, LendingIterator, LevelOrder,
+    PostOrder, PreOrder, Traverse, TraversalOrder, TraverseMut,
+};
+use std::iter::Map;
+use std::ops::RangeBounds;
 use std::marker::PhantomData;
+use std::str::FromStr;
 
 type Color = bool;
 
@@ -29,16 +42,115 @@ where
     }
 }
 
+impl<Value> Data<Value>
+where
+    Value: Clone,
+{
+    pub(crate) fn value(&self) -> &Value {
+        &self.value
+    }
+}
+
+fn strip_color<Key, Value>((key, data): (Key, Data<Value>)) -> (Key, Value)
+where
+    Value: Clone,
+{
+    (key, data.value)
+}
+
+fn strip_color_ref<'a, Key, Value, G>(
+    (key, data): (&'a Key, GuardRef<'a, G, Data<Value>>),
+) -> (&'a Key, GuardRef<'a, G, Value>)
+where
+    Value: Clone,
+    G: Guards,
+{
+    (key, G::map(data, |data| &data.value))
+}
+
+fn strip_color_mut<'a, Key, Value, G>(
+    (key, data): (&'a Key, GuardRefMut<'a, G, Data<Value>>),
+) -> (&'a Key, GuardRefMut<'a, G, Value>)
+where
+    Value: Clone,
+    G: Guards,
+{
+    (key, G::map_mut(data, |data| &mut data.value))
+}
+
+pub struct StripColorMut<'a, Key, Value, NodeId, ImplTree, Order>
+where
+    Value: Clone,
+{
+    traverse: TraverseMut<'a, Key, Data<Value>, NodeId, ImplTree, Order>,
+}
+
+impl<Key, Value, NodeId, ImplTree, Order> LendingIterator
+    for StripColorMut<'_, Key, Value, NodeId, ImplTree, Order>
+where
+    Value: Clone,
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Data<Value>, NodeId>,
+    Order: TraversalOrder<Key, Data<Value>, NodeId, ImplTree>,
+{
+    type Item<'b>
+        = (&'b Key, GuardRefMut<'b, ImplTree::Guards, Value>)
+    where
+        Self: 'b;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.traverse.next().map(strip_color_mut::<_, _, ImplTree::Guards>)
+    }
+}
+
+impl<Key, Value, NodeId, ImplTree, Order> DoubleEndedLendingIterator
+    for StripColorMut<'_, Key, Value, NodeId, ImplTree, Order>
+where
+    Value: Clone,
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Data<Value>, NodeId>,
+    Order: DoubleEndedTraversalOrder<Key, Data<Value>, NodeId, ImplTree>,
+{
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        self.traverse.next_back().map(strip_color_mut::<_, _, ImplTree::Guards>)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Violation<NodeId> {
+    RedRoot(NodeId),
+    RedSonOfRedNode(NodeId),
+    BlackHeightMismatch(NodeId),
+    BrokenParentLink(NodeId),
+}
+
+impl<NodeId> std::fmt::Display for Violation<NodeId>
+where
+    NodeId: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::RedRoot(node_id) => write!(f, "root {:?} is red", node_id),
+            Self::RedSonOfRedNode(node_id) => write!(f, "red node {:?} has a red parent", node_id),
+            Self::BlackHeightMismatch(node_id) => {
+                write!(f, "subtrees of {:?} have different black heights", node_id)
+            }
+            Self::BrokenParentLink(node_id) => write!(f, "node {:?} has a wrong parent link", node_id),
+        }
+    }
+}
+
 pub struct RedBlackTree<Key, Value, ImplTree, NodeId>
 where
     Key: Ord,
     Value: Clone,
-    NodeId: Default + Clone + Copy + PartialEq + Eq,
+    NodeId: Default + Clone + PartialEq + Eq,
     ImplTree: Default
         + Tree<Key, Data<Value>>
         + NodeIdentifiableTree<Key, Data<Value>, NodeId>
         + ParentifiedTree<Key, Data<Value>, NodeId>
-        + RotatableTree<Key, Data<Value>, NodeId>,
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>,
 {
     bst: ImplTree,
     _phantom_key: PhantomData<Key>,
@@ -50,12 +162,13 @@ impl<Key, Value, ImplTree, NodeId> Default for RedBlackTree<Key, Value, ImplTree
 where
     Key: Ord,
     Value: Clone,
-    NodeId: Default + Clone + Copy + PartialEq + Eq,
+    NodeId: Default + Clone + PartialEq + Eq,
     ImplTree: Default
         + Tree<Key, Data<Value>>
         + NodeIdentifiableTree<Key, Data<Value>, NodeId>
         + ParentifiedTree<Key, Data<Value>, NodeId>
-        + RotatableTree<Key, Data<Value>, NodeId>,
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>,
 {
     fn default() -> Self {
         Self {
@@ -67,19 +180,293 @@ where
     }
 }
 
+impl<Key, Value, ImplTree, NodeId> RedBlackTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>,
+{
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_observer(observer: ImplTree::Observer) -> Self {
+        Self {
+            bst: ImplTree::with_observer(observer),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn bst(&self) -> &ImplTree {
+        &self.bst
+    }
+
+    pub fn observer(&self) -> &ImplTree::Observer {
+        self.bst.observer()
+    }
+
+    pub fn observer_mut(&mut self) -> &mut ImplTree::Observer {
+        self.bst.observer_mut()
+    }
+
+    fn get_color(&self, node_id: Option<&NodeId>) -> Color {
+        node_id
+            .and_then(|node_id| self.bst.get_by_id(node_id.clone()).map(|data| data.color))
+            .unwrap_or(BLACK)
+    }
+
+    fn set_color(&mut self, node_id: Option<&NodeId>, color: Color) {
+        if let Some(node_id) = node_id
+            && self.get_color(Some(node_id)) != color
+        {
+            self.bst.modify(node_id.clone(), |data| data.color = color);
+            self.bst.notify(node_id.clone(), Event::Recolor { red: color == RED });
+        }
+    }
+
+    fn get_left_son_color(&self, node_id: &NodeId) -> Color {
+        self.get_color(self.bst.get_left_son_id(node_id.clone()).as_ref())
+    }
+
+    fn get_right_son_color(&self, node_id: &NodeId) -> Color {
+        self.get_color(self.bst.get_right_son_id(node_id.clone()).as_ref())
+    }
+
+    fn get_minimum_id(&self, mut node_id: NodeId) -> NodeId {
+        while let Some(left_son_id) = self.bst.get_left_son_id(node_id.clone()) {
+            node_id = left_son_id;
+        }
+
+        node_id
+    }
+
+    fn insert_fixup(&mut self, mut z_id: NodeId) {
+        while let Some(z_parent_id) = self.bst.get_parent_id(z_id.clone())
+            && self.get_color(Some(&z_parent_id)) == RED
+        {
+            let z_grand_parent_id = self
+                .bst
+                .get_parent_id(z_parent_id.clone())
+                .expect("Red node must have a parent");
+
+            if self.bst.is_left_son(z_parent_id.clone()) {
+                let z_uncle_id = self.bst.get_right_son_id(z_grand_parent_id.clone());
+
+                if self.get_color(z_uncle_id.as_ref()) == RED {
+                    self.set_color(Some(&z_parent_id), BLACK);
+                    self.set_color(z_uncle_id.as_ref(), BLACK);
+                    self.set_color(Some(&z_grand_parent_id), RED);
+                    z_id = z_grand_parent_id;
+                    continue;
+                }
+
+                let mut z_parent_id = z_parent_id;
+                if self.bst.is_right_son(z_id.clone()) {
+                    z_id = z_parent_id;
+                    self.bst.left_rotate(z_id.clone());
+                    z_parent_id = self.bst.get_parent_id(z_id.clone()).expect("Parent not found");
+                }
+
+                self.set_color(Some(&z_parent_id), BLACK);
+                self.set_color(Some(&z_grand_parent_id), RED);
+                self.bst.right_rotate(z_grand_parent_id);
+            } else {
+                let z_uncle_id = self.bst.get_left_son_id(z_grand_parent_id.clone());
+
+                if self.get_color(z_uncle_id.as_ref()) == RED {
+                    self.set_color(Some(&z_parent_id), BLACK);
+                    self.set_color(z_uncle_id.as_ref(), BLACK);
+                    self.set_color(Some(&z_grand_parent_id), RED);
+                    z_id = z_grand_parent_id;
+                    continue;
+                }
+
+                let mut z_parent_id = z_parent_id;
+                if self.bst.is_left_son(z_id.clone()) {
+                    z_id = z_parent_id;
+                    self.bst.right_rotate(z_id.clone());
+                    z_parent_id = self.bst.get_parent_id(z_id.clone()).expect("Parent not found");
+                }
+
+                self.set_color(Some(&z_parent_id), BLACK);
+                self.set_color(Some(&z_grand_parent_id), RED);
+                self.bst.left_rotate(z_grand_parent_id);
+            }
+        }
+
+        self.set_color(self.bst.get_root_id().as_ref(), BLACK);
+    }
+
+    fn delete_fixup(&mut self, mut x_id: Option<NodeId>, mut x_parent_id: Option<NodeId>) {
+        while x_id != self.bst.get_root_id() && self.get_color(x_id.as_ref()) == BLACK {
+            let Some(parent_id) = x_parent_id else {
+                break;
+            };
+
+            if x_id == self.bst.get_left_son_id(parent_id.clone()) {
+                let mut sibling_id = self.bst.get_right_son_id(parent_id.clone());
+
+                if self.get_color(sibling_id.as_ref()) == RED {
+                    self.set_color(sibling_id.as_ref(), BLACK);
+                    self.set_color(Some(&parent_id), RED);
+                    self.bst.left_rotate(parent_id.clone());
+                    sibling_id = self.bst.get_right_son_id(parent_id.clone());
+                }
+
+                let Some(mut w_id) = sibling_id else {
+                    break;
+                };
+
+                if self.get_left_son_color(&w_id) == BLACK && self.get_right_son_color(&w_id) == BLACK {
+                    self.set_color(Some(&w_id), RED);
+                    x_parent_id = self.bst.get_parent_id(parent_id.clone());
+                    x_id = Some(parent_id);
+                } else {
+                    if self.get_right_son_color(&w_id) == BLACK {
+                        self.set_color(self.bst.get_left_son_id(w_id.clone()).as_ref(), BLACK);
+                        self.set_color(Some(&w_id), RED);
+                        self.bst.right_rotate(w_id);
+                        w_id = self
+                            .bst
+                            .get_right_son_id(parent_id.clone())
+                            .expect("Sibling not found");
+                    }
+
+                    self.set_color(Some(&w_id), self.get_color(Some(&parent_id)));
+                    self.set_color(Some(&parent_id), BLACK);
+                    self.set_color(self.bst.get_right_son_id(w_id).as_ref(), BLACK);
+                    self.bst.left_rotate(parent_id);
+                    x_id = self.bst.get_root_id();
+                    x_parent_id = None;
+                }
+            } else {
+                let mut sibling_id = self.bst.get_left_son_id(parent_id.clone());
+
+                if self.get_color(sibling_id.as_ref()) == RED {
+                    self.set_color(sibling_id.as_ref(), BLACK);
+                    self.set_color(Some(&parent_id), RED);
+                    self.bst.right_rotate(parent_id.clone());
+                    sibling_id = self.bst.get_left_son_id(parent_id.clone());
+                }
+
+                let Some(mut w_id) = sibling_id else {
+                    break;
+                };
+
+                if self.get_left_son_color(&w_id) == BLACK && self.get_right_son_color(&w_id) == BLACK {
+                    self.set_color(Some(&w_id), RED);
+                    x_parent_id = self.bst.get_parent_id(parent_id.clone());
+                    x_id = Some(parent_id);
+                } else {
+                    if self.get_left_son_color(&w_id) == BLACK {
+                        self.set_color(self.bst.get_right_son_id(w_id.clone()).as_ref(), BLACK);
+                        self.set_color(Some(&w_id), RED);
+                        self.bst.left_rotate(w_id);
+                        w_id = self
+                            .bst
+                            .get_left_son_id(parent_id.clone())
+                            .expect("Sibling not found");
+                    }
+
+                    self.set_color(Some(&w_id), self.get_color(Some(&parent_id)));
+                    self.set_color(Some(&parent_id), BLACK);
+                    self.set_color(self.bst.get_left_son_id(w_id).as_ref(), BLACK);
+                    self.bst.right_rotate(parent_id);
+                    x_id = self.bst.get_root_id();
+                    x_parent_id = None;
+                }
+            }
+        }
+
+        self.set_color(x_id.as_ref(), BLACK);
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> RedBlackTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>,
+{
+    pub fn validate(&self) -> Result<usize, Violation<NodeId>> {
+        let Some(root_id) = self.bst.get_root_id() else {
+            return Ok(0);
+        };
+
+        if self.bst.get_parent_id(root_id.clone()).is_some() {
+            return Err(Violation::BrokenParentLink(root_id));
+        }
+
+        if self.get_color(Some(&root_id)) == RED {
+            return Err(Violation::RedRoot(root_id));
+        }
+
+        self.validate_subtree(root_id)
+    }
+
+    fn validate_subtree(&self, node_id: NodeId) -> Result<usize, Violation<NodeId>> {
+        let color = self.get_color(Some(&node_id));
+        let mut black_heights = [0; 2];
+
+        for (black_height, son_id) in black_heights.iter_mut().zip([
+            self.bst.get_left_son_id(node_id.clone()),
+            self.bst.get_right_son_id(node_id.clone()),
+        ]) {
+            let Some(son_id) = son_id else {
+                continue;
+            };
+
+            if self.bst.get_parent_id(son_id.clone()).as_ref() != Some(&node_id) {
+                return Err(Violation::BrokenParentLink(son_id));
+            }
+
+            if color == RED && self.get_color(Some(&son_id)) == RED {
+                return Err(Violation::RedSonOfRedNode(son_id));
+            }
+
+            *black_height = self.validate_subtree(son_id)?;
+        }
+
+        if black_heights[0] != black_heights[1] {
+            return Err(Violation::BlackHeightMismatch(node_id));
+        }
+
+        Ok(black_heights[0] + usize::from(color == BLACK))
+    }
+}
+
 impl<Key, Value, ImplTree, NodeId> Tree<Key, Value> for RedBlackTree<Key, Value, ImplTree, NodeId>
 where
     Key: Ord,
     Value: Clone,
-    NodeId: Default + Clone + Copy + PartialEq + Eq,
+    NodeId: Default + Clone + PartialEq + Eq,
     ImplTree: Default
         + Tree<Key, Data<Value>>
         + NodeIdentifiableTree<Key, Data<Value>, NodeId>
         + ParentifiedTree<Key, Data<Value>, NodeId>
-        + RotatableTree<Key, Data<Value>, NodeId>,
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>,
 {
     fn insert(&mut self, key: Key, val: Value) {
-        let _ = self.bst.insert_and_get_id(
+        if let Some(node_id) = self.bst.get_id(&key) {
+            let color = self.get_color(Some(&node_id));
+            self.bst.insert_and_get_id(key, Data { value: val, color });
+            return;
+        }
+
+        let z_id = self.bst.insert_and_get_id(
             key,
             Data {
                 value: val,
@@ -87,40 +474,7 @@ where
             },
         );
 
-        // while let Some(z_node) = self.bst.get_by_id(z_node_id)
-        //     && z_node.color == RED
-        //     && self.bst.get_root_id() != Some(z_node_id)
-        // {
-        //     let z_parent_id = self.bst.get_parent_id(z_node_id).unwrap();
-        //
-        //     if self.bst.is_left_son(z_parent_id) {
-        //         if let Some(z_right_uncle_id) = self.bst.get_right_uncle_id(z_node_id)
-        //             && let Some(z_right_uncle) = self.bst.get_by_id(z_right_uncle_id)
-        //             && z_right_uncle.color == RED
-        //         {
-        //             if let Some(z_grand_parent_id) = self.bst.get_parent_id(z_parent_id) {
-        //                 self.bst.modify(z_parent_id, |mut val| val.color = BLACK);
-        //                 self.bst.modify(z_right_uncle_id, |mut val| val.color = BLACK);
-        //                 self.bst.modify(z_grand_parent_id, |mut val| val.color = RED);
-        //                 z_node_id = z_grand_parent_id;
-        //             }
-        //         } else if self.bst.is_right_son(z_node_id) {
-        //             z_node_id = z_parent_id;
-        //             self.bst.left_rotate(z_parent_id);
-        //         } else if let Some(z_grand_parent_id) = self.bst.get_parent_id(z_parent_id) {
-        //             z_node_id = z_parent_id;
-        //             self.bst.modify(z_parent_id, |mut val| val.color = BLACK);
-        //             self.bst.modify(z_grand_parent_id, |mut val| val.color = RED);
-        //             self.bst.right_rotate(z_grand_parent_id);
-        //         }
-        //     } else if self.bst.is_right_son(z_parent_id) {
-        //     } else {
-        //         break;
-        //     }
-        // }
-
-        let mut root = self.bst.get_root_mut().unwrap();
-        (*root).color = BLACK;
+        self.insert_fixup(z_id);
     }
 
     fn get(&self, key: &Key) -> Option<Value> {
@@ -128,7 +482,323 @@ where
     }
 
     fn delete(&mut self, key: &Key) -> Option<Value> {
-        todo!()
+        let z_id = self.bst.get_id(key)?;
+        let z_color = self.get_color(Some(&z_id));
+
+        let (successor_id, removed_color, x_id, x_parent_id) = match (
+            self.bst.get_left_son_id(z_id.clone()),
+            self.bst.get_right_son_id(z_id.clone()),
+        ) {
+            (None, right_son_id) => (
+                None,
+                z_color,
+                right_son_id,
+                self.bst.get_parent_id(z_id.clone()),
+            ),
+            (left_son_id, None) => (
+                None,
+                z_color,
+                left_son_id,
+                self.bst.get_parent_id(z_id.clone()),
+            ),
+            (Some(_), Some(right_son_id)) => {
+                let y_id = self.get_minimum_id(right_son_id);
+                let y_parent_id = self.bst.get_parent_id(y_id.clone());
+                let x_parent_id = if y_parent_id.as_ref() == Some(&z_id) {
+                    Some(y_id.clone())
+                } else {
+                    y_parent_id
+                };
+
+                (
+                    Some(y_id.clone()),
+                    self.get_color(Some(&y_id)),
+                    self.bst.get_right_son_id(y_id),
+                    x_parent_id,
+                )
+            }
+        };
+
+        let removed = self.bst.delete_by_id(z_id)?;
+        self.set_color(successor_id.as_ref(), z_color);
+
+        if removed_color == BLACK {
+            self.delete_fixup(x_id, x_parent_id);
+        }
+
+        Some(removed.value)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> OrderedTree<Key, Value>
+    for RedBlackTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + OrderedTree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>,
+{
+    fn min(&self) -> Option<(Key, Value)> {
+        self.bst.min().map(strip_color)
+    }
+
+    fn max(&self) -> Option<(Key, Value)> {
+        self.bst.max().map(strip_color)
+    }
+
+    fn floor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.floor(key).map(strip_color)
+    }
+
+    fn ceiling(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.ceiling(key).map(strip_color)
+    }
+
+    fn successor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.successor(key).map(strip_color)
+    }
+
+    fn predecessor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.predecessor(key).map(strip_color)
+    }
+
+    fn range<R>(&self, range: R) -> impl Iterator<Item = (Key, Value)>
+    where
+        R: RangeBounds<Key>,
+    {
+        self.bst.range(range).map(strip_color)
+    }
+
+    fn rank(&self, key: &Key) -> usize {
+        self.bst.rank(key)
+    }
+
+    fn select(&self, rank: usize) -> Option<(Key, Value)> {
+        self.bst.select(rank).map(strip_color)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> RedBlackTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>
+        + TraversableTree<Key, Data<Value>, NodeId>,
+{
+    pub fn in_order(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (&Key, GuardRef<'_, ImplTree::Guards, Value>)> {
+        self.bst.in_order().map(strip_color_ref::<_, _, ImplTree::Guards>)
+    }
+
+    pub fn pre_order(&self) -> impl Iterator<Item = (&Key, GuardRef<'_, ImplTree::Guards, Value>)> {
+        self.bst.pre_order().map(strip_color_ref::<_, _, ImplTree::Guards>)
+    }
+
+    pub fn post_order(
+        &self,
+    ) -> impl Iterator<Item = (&Key, GuardRef<'_, ImplTree::Guards, Value>)> {
+        self.bst.post_order().map(strip_color_ref::<_, _, ImplTree::Guards>)
+    }
+
+    pub fn level_order(
+        &self,
+    ) -> impl Iterator<Item = (&Key, GuardRef<'_, ImplTree::Guards, Value>)> {
+        self.bst.level_order().map(strip_color_ref::<_, _, ImplTree::Guards>)
+    }
+
+    pub fn in_order_mut(
+        &mut self,
+    ) -> StripColorMut<'_, Key, Value, NodeId, ImplTree, InOrder<NodeId>> {
+        StripColorMut {
+            traverse: self.bst.in_order_mut(),
+        }
+    }
+
+    pub fn in_order_for_each_mut<F>(&mut self, mut visitor: F)
+    where
+        F: FnMut(&Key, &mut Value),
+    {
+        self.bst.in_order_for_each_mut(|key, data| visitor(key, &mut data.value));
+    }
+
+    pub fn pre_order_mut(
+        &mut self,
+    ) -> StripColorMut<'_, Key, Value, NodeId, ImplTree, PreOrder<NodeId>> {
+        StripColorMut {
+            traverse: self.bst.pre_order_mut(),
+        }
+    }
+
+    pub fn pre_order_for_each_mut<F>(&mut self, mut visitor: F)
+    where
+        F: FnMut(&Key, &mut Value),
+    {
+        self.bst.pre_order_for_each_mut(|key, data| visitor(key, &mut data.value));
+    }
+
+    pub fn post_order_mut(
+        &mut self,
+    ) -> StripColorMut<'_, Key, Value, NodeId, ImplTree, PostOrder<NodeId>> {
+        StripColorMut {
+            traverse: self.bst.post_order_mut(),
+        }
+    }
+
+    pub fn post_order_for_each_mut<F>(&mut self, mut visitor: F)
+    where
+        F: FnMut(&Key, &mut Value),
+    {
+        self.bst.post_order_for_each_mut(|key, data| visitor(key, &mut data.value));
+    }
+
+    pub fn level_order_mut(
+        &mut self,
+    ) -> StripColorMut<'_, Key, Value, NodeId, ImplTree, LevelOrder<NodeId>> {
+        StripColorMut {
+            traverse: self.bst.level_order_mut(),
+        }
+    }
+
+    pub fn level_order_for_each_mut<F>(&mut self, mut visitor: F)
+    where
+        F: FnMut(&Key, &mut Value),
+    {
+        self.bst.level_order_for_each_mut(|key, data| visitor(key, &mut data.value));
+    }
+}
+
+impl<'a, Key, Value, ImplTree, NodeId> IntoIterator for &'a RedBlackTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>
+        + TraversableTree<Key, Data<Value>, NodeId>,
+{
+    type Item = (&'a Key, GuardRef<'a, ImplTree::Guards, Value>);
+    type IntoIter = Map<
+        Traverse<'a, Key, Data<Value>, NodeId, ImplTree, InOrder<NodeId>>,
+        fn(
+            (&'a Key, GuardRef<'a, ImplTree::Guards, Data<Value>>),
+        ) -> (&'a Key, GuardRef<'a, ImplTree::Guards, Value>),
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bst.in_order().map(strip_color_ref::<_, _, ImplTree::Guards>)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> IntoIterator for RedBlackTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>
+        + IntoIterator<Item = (Key, Data<Value>)>,
+{
+    type Item = (Key, Value);
+    type IntoIter = Map<<ImplTree as IntoIterator>::IntoIter, fn((Key, Data<Value>)) -> (Key, Value)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bst.into_iter().map(strip_color)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> RedBlackTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>
+        + BulkLoadableTree<Key, Data<Value>>,
+{
+    fn color_balanced(&mut self) {
+        let Some(root_id) = self.bst.get_root_id() else {
+            return;
+        };
+
+        // A perfectly balanced tree is complete above its deepest level, so painting only
+        // that level red (unless it is full) keeps every black height equal.
+        let red_depth = (self.bst.get_subtree_size(root_id.clone()) + 1).ilog2();
+        let mut stack = vec![(root_id, 0)];
+
+        while let Some((node_id, depth)) = stack.pop() {
+            let color = if depth >= red_depth { RED } else { BLACK };
+            self.bst.modify(node_id.clone(), |data| data.color = color);
+
+            for son_id in [
+                self.bst.get_left_son_id(node_id.clone()),
+                self.bst.get_right_son_id(node_id),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                stack.push((son_id, depth + 1));
+            }
+        }
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> BulkLoadableTree<Key, Value>
+    for RedBlackTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>
+        + BulkLoadableTree<Key, Data<Value>>,
+{
+    fn from_sorted_iter<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (Key, Value)>,
+    {
+        let entries = entries
+            .into_iter()
+            .map(|(key, value)| (key, Data { value, color: BLACK }));
+
+        let mut tree = Self {
+            bst: ImplTree::from_sorted_iter(entries),
+            ..Default::default()
+        };
+        tree.color_balanced();
+        tree
+    }
+
+    fn rebuild_balanced(&mut self) {
+        self.bst.rebuild_balanced();
+        self.color_balanced();
     }
 }
 
@@ -136,15 +806,208 @@ impl<Key, Value, ImplTree, NodeId> std::fmt::Display for RedBlackTree<Key, Value
 where
     Key: Ord + std::fmt::Display,
     Value: Clone + std::fmt::Display,
-    NodeId: Default + Clone + Copy + PartialEq + Eq,
+    NodeId: Default + Clone + PartialEq + Eq,
     ImplTree: Default
         + Tree<Key, Data<Value>>
         + NodeIdentifiableTree<Key, Data<Value>, NodeId>
         + ParentifiedTree<Key, Data<Value>, NodeId>
         + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>
         + std::fmt::Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}", self.bst)
     }
 }
+
+impl<Key, Value, ImplTree, NodeId> RedBlackTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord + std::fmt::Display,
+    Value: Clone + std::fmt::Display,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>,
+{
+    pub fn to_dot(&self) -> String {
+        export::to_dot(&self.bst, |data| {
+            let attributes = if data.color == RED {
+                ", style=filled, fillcolor=red, fontcolor=white"
+            } else {
+                ", style=filled, fillcolor=black, fontcolor=white"
+            };
+
+            (data.value.to_string(), attributes)
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        export::to_json(&self.bst, |data| {
+            vec![
+                ("value", data.value.to_string()),
+                ("color", if data.color == RED { "red" } else { "black" }.to_string()),
+            ]
+        })
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> RedBlackTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord + std::fmt::Display + FromStr,
+    Value: Clone + FromStr,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + ObservableTree<Key, NodeId>,
+{
+    pub fn from_json(json: &str) -> Result<Self, LoadError> {
+        let bst = export::from_json(json, |fields| {
+            let color = match fields.get_str("color")? {
+                "red" => RED,
+                "black" => BLACK,
+                other => {
+                    return Err(LoadError::InvalidField("color".to_string(), other.to_string()));
+                }
+            };
+
+            Ok(Data {
+                value: fields.parse("value")?,
+                color,
+            })
+        })?;
+
+        let tree = Self {
+            bst,
+            ..Default::default()
+        };
+
+        if let Err(violation) = tree.validate() {
+            let node_id = match violation {
+                Violation::RedRoot(node_id)
+                | Violation::RedSonOfRedNode(node_id)
+                | Violation::BlackHeightMismatch(node_id)
+                | Violation::BrokenParentLink(node_id) => node_id,
+            };
+            let key = tree.bst.get_key_by_id(node_id).expect("Node not found");
+            return Err(LoadError::InvalidColors(key.to_string()));
+        }
+
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{XorShift64, check_random_insert_delete};
+    use crate::traits::{BulkLoadableTree, Tree};
+    use crate::{ArrayRedBlackTree, ListRedBlackTree};
+    use std::collections::BTreeMap;
+
+    macro_rules! red_black_tree_tests {
+        ($($backend:ident: $tree:ident,)*) => {$(
+            mod $backend {
+                use super::*;
+
+                #[test]
+                fn test_insert() {
+                    let mut tree = $tree::<i32, i32>::default();
+                    assert_eq!(tree.validate(), Ok(0));
+
+                    for key in 0..1024 {
+                        tree.insert(key, key * 10);
+                        assert!(tree.validate().is_ok());
+                    }
+
+                    let black_height = tree.validate().unwrap();
+                    assert!((5..=10).contains(&black_height));
+
+                    tree.insert(512, 0);
+                    assert_eq!(tree.get(&512), Some(0));
+                    assert_eq!(tree.validate(), Ok(black_height));
+
+                    for key in (1024..2048).rev() {
+                        tree.insert(key, key * 10);
+                    }
+
+                    assert!(tree.validate().is_ok());
+                    for key in 1..2048 {
+                        assert_eq!(tree.get(&key), Some(if key == 512 { 0 } else { key * 10 }));
+                    }
+                }
+
+                #[test]
+                fn test_delete() {
+                    let mut tree = $tree::<i32, i32>::default();
+                    for key in 1..8 {
+                        tree.insert(key, key * 10);
+                    }
+
+                    assert_eq!(tree.delete(&1), Some(10));
+                    assert_eq!(tree.delete(&4), Some(40));
+                    assert_eq!(tree.delete(&4), None);
+                    assert_eq!(tree.delete(&2), Some(20));
+                    assert!(tree.validate().is_ok());
+
+                    for key in [3, 5, 6, 7] {
+                        assert_eq!(tree.get(&key), Some(key * 10));
+                    }
+                }
+
+                #[test]
+                fn test_random_insert_delete() {
+                    check_random_insert_delete($tree::<i32, u64>::validate);
+                }
+
+                #[test]
+                fn test_from_sorted_iter() {
+                    for len in 0..300 {
+                        let entries = (0..len).map(|key| (key, -key));
+                        let tree = $tree::<i32, i32>::from_sorted_iter(entries.clone());
+
+                        if let Err(violation) = tree.validate() {
+                            panic!("len {}: {}", len, violation);
+                        }
+                        assert!(tree.into_iter().eq(entries));
+                    }
+                }
+
+                #[test]
+                fn test_rebuild_balanced() {
+                    let mut random = XorShift64::new(0xBA1);
+                    let mut tree = $tree::<i32, u64>::default();
+                    let mut model = BTreeMap::new();
+
+                    for step in 0..3000 {
+                        let key = random.next_below(512) as i32;
+                        if random.next_below(3) == 0 {
+                            assert_eq!(tree.delete(&key), model.remove(&key));
+                        } else {
+                            tree.insert(key, step);
+                            model.insert(key, step);
+                        }
+
+                        if step % 500 == 0 {
+                            tree.rebuild_balanced();
+                            if let Err(violation) = tree.validate() {
+                                panic!("step {}: {}", step, violation);
+                            }
+                        }
+                    }
+
+                    assert!(tree.into_iter().eq(model.into_iter()));
+                }
+            }
+        )*};
+    }
+
+    red_black_tree_tests! {
+        array_backend: ArrayRedBlackTree,
+        list_backend: ListRedBlackTree,
+    }
+}
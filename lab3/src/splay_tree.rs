@@ -0,0 +1,337 @@
+use crate::ordered_tree;
+use crate::traits::{NodeIdentifiableTree, OrderedTree, ParentifiedTree, RotatableTree, Tree};
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Violation<NodeId> {
+    KeysOutOfOrder(NodeId),
+    BrokenParentLink(NodeId),
+}
+
+impl<NodeId> std::fmt::Display for Violation<NodeId>
+where
+    NodeId: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::KeysOutOfOrder(node_id) => {
+                write!(f, "key of {:?} is out of order with its ancestors", node_id)
+            }
+            Self::BrokenParentLink(node_id) => {
+                write!(f, "node {:?} has a wrong parent link", node_id)
+            }
+        }
+    }
+}
+
+pub struct SplayTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Value>
+        + NodeIdentifiableTree<Key, Value, NodeId>
+        + ParentifiedTree<Key, Value, NodeId>
+        + RotatableTree<Key, Value, NodeId>,
+{
+    bst: ImplTree,
+    _phantom_key: PhantomData<Key>,
+    _phantom_value: PhantomData<Value>,
+    _phantom_node_id: PhantomData<NodeId>,
+}
+
+impl<Key, Value, ImplTree, NodeId> Default for SplayTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Value>
+        + NodeIdentifiableTree<Key, Value, NodeId>
+        + ParentifiedTree<Key, Value, NodeId>
+        + RotatableTree<Key, Value, NodeId>,
+{
+    fn default() -> Self {
+        Self {
+            bst: Default::default(),
+            _phantom_key: Default::default(),
+            _phantom_value: Default::default(),
+            _phantom_node_id: Default::default(),
+        }
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> SplayTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Value>
+        + NodeIdentifiableTree<Key, Value, NodeId>
+        + ParentifiedTree<Key, Value, NodeId>
+        + RotatableTree<Key, Value, NodeId>,
+{
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    fn rotate_up(bst: &mut ImplTree, node_id: NodeId, parent_id: NodeId) {
+        if bst.is_left_son(node_id) {
+            bst.right_rotate(parent_id);
+        } else {
+            bst.left_rotate(parent_id);
+        }
+    }
+
+    fn splay(bst: &mut ImplTree, node_id: NodeId) {
+        while let Some(parent_id) = bst.get_parent_id(node_id.clone()) {
+            let Some(grand_parent_id) = bst.get_parent_id(parent_id.clone()) else {
+                Self::rotate_up(bst, node_id.clone(), parent_id);
+                break;
+            };
+
+            if bst.is_left_son(node_id.clone()) == bst.is_left_son(parent_id.clone()) {
+                Self::rotate_up(bst, parent_id.clone(), grand_parent_id);
+                Self::rotate_up(bst, node_id.clone(), parent_id);
+            } else {
+                Self::rotate_up(bst, node_id.clone(), parent_id);
+                Self::rotate_up(bst, node_id.clone(), grand_parent_id);
+            }
+        }
+    }
+
+    fn splay_closest(bst: &mut ImplTree, key: &Key) -> Option<NodeId> {
+        let node_id = bst.get_id(key);
+        let closest_id = node_id.clone().or_else(|| {
+            ordered_tree::get_floor_id(bst, key, true)
+                .or_else(|| ordered_tree::get_ceiling_id(bst, key, true))
+        });
+
+        if let Some(closest_id) = closest_id {
+            Self::splay(bst, closest_id);
+        }
+
+        node_id
+    }
+
+    pub fn access(&mut self, key: &Key) -> Option<Value> {
+        let node_id = Self::splay_closest(&mut self.bst, key)?;
+        self.bst.get_by_id(node_id).map(|value| value.clone())
+    }
+
+    pub fn validate(&self) -> Result<usize, Violation<NodeId>> {
+        let bst = &self.bst;
+        let Some(root_id) = bst.get_root_id() else {
+            return Ok(0);
+        };
+
+        if bst.get_parent_id(root_id.clone()).is_some() {
+            return Err(Violation::BrokenParentLink(root_id));
+        }
+
+        Self::validate_subtree(bst, root_id, None, None)
+    }
+
+    fn validate_subtree(
+        bst: &ImplTree,
+        node_id: NodeId,
+        lower_bound: Option<&Key>,
+        upper_bound: Option<&Key>,
+    ) -> Result<usize, Violation<NodeId>> {
+        let key = bst.get_key_by_id(node_id.clone()).expect("Key not found");
+
+        if lower_bound.is_some_and(|lower_bound| key <= lower_bound)
+            || upper_bound.is_some_and(|upper_bound| key >= upper_bound)
+        {
+            return Err(Violation::KeysOutOfOrder(node_id));
+        }
+
+        let mut height = 0;
+
+        for (son_id, lower_bound, upper_bound) in [
+            (bst.get_left_son_id(node_id.clone()), lower_bound, Some(key)),
+            (
+                bst.get_right_son_id(node_id.clone()),
+                Some(key),
+                upper_bound,
+            ),
+        ] {
+            let Some(son_id) = son_id else {
+                continue;
+            };
+
+            if bst.get_parent_id(son_id.clone()).as_ref() != Some(&node_id) {
+                return Err(Violation::BrokenParentLink(son_id));
+            }
+
+            height = height.max(Self::validate_subtree(
+                bst,
+                son_id,
+                lower_bound,
+                upper_bound,
+            )?);
+        }
+
+        Ok(height + 1)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> Tree<Key, Value> for SplayTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Value>
+        + NodeIdentifiableTree<Key, Value, NodeId>
+        + ParentifiedTree<Key, Value, NodeId>
+        + RotatableTree<Key, Value, NodeId>,
+{
+    fn insert(&mut self, key: Key, val: Value) {
+        let node_id = self.bst.insert_and_get_id(key, val);
+        Self::splay(&mut self.bst, node_id);
+    }
+
+    fn get(&self, key: &Key) -> Option<Value> {
+        let node_id = self.bst.get_id(key)?;
+        self.bst.get_by_id(node_id).map(|value| value.clone())
+    }
+
+    fn delete(&mut self, key: &Key) -> Option<Value> {
+        let node_id = Self::splay_closest(&mut self.bst, key)?;
+        self.bst.delete_by_id(node_id)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> OrderedTree<Key, Value>
+    for SplayTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + OrderedTree<Key, Value>
+        + NodeIdentifiableTree<Key, Value, NodeId>
+        + ParentifiedTree<Key, Value, NodeId>
+        + RotatableTree<Key, Value, NodeId>,
+{
+    fn min(&self) -> Option<(Key, Value)> {
+        self.bst.min()
+    }
+
+    fn max(&self) -> Option<(Key, Value)> {
+        self.bst.max()
+    }
+
+    fn floor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.floor(key)
+    }
+
+    fn ceiling(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.ceiling(key)
+    }
+
+    fn successor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.successor(key)
+    }
+
+    fn predecessor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.predecessor(key)
+    }
+
+    fn range<R>(&self, range: R) -> impl Iterator<Item = (Key, Value)>
+    where
+        R: RangeBounds<Key>,
+    {
+        self.bst.range(range)
+    }
+
+    fn rank(&self, key: &Key) -> usize {
+        self.bst.rank(key)
+    }
+
+    fn select(&self, rank: usize) -> Option<(Key, Value)> {
+        self.bst.select(rank)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> std::fmt::Display for SplayTree<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord + std::fmt::Display,
+    Value: Clone + std::fmt::Display,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Value>
+        + NodeIdentifiableTree<Key, Value, NodeId>
+        + ParentifiedTree<Key, Value, NodeId>
+        + RotatableTree<Key, Value, NodeId>
+        + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.bst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{check_random_insert_delete, check_sequential_insert};
+    use crate::traits::{NodeIdentifiableTree, Tree};
+    use crate::{ArraySplayTree, ListSplayTree};
+
+    macro_rules! splay_tree_tests {
+        ($($backend:ident: $tree:ident,)*) => {$(
+            mod $backend {
+                use super::*;
+
+                fn root_key(tree: &$tree<i32, u64>) -> Option<i32> {
+                    let bst = &tree.bst;
+                    bst.get_root_id().and_then(|root_id| bst.get_key_by_id(root_id).copied())
+                }
+
+                #[test]
+                fn test_insert() {
+                    let tree = check_sequential_insert($tree::<i32, u64>::validate);
+                    assert!(tree.validate().is_ok());
+                }
+
+                #[test]
+                fn test_splay_on_access() {
+                    let mut tree = $tree::<i32, u64>::default();
+                    for key in (0..100).map(|key| key * 2) {
+                        tree.insert(key, key as u64);
+                        assert_eq!(root_key(&tree), Some(key));
+                    }
+
+                    assert_eq!(tree.validate(), Ok(100));
+
+                    assert_eq!(tree.get(&0), Some(0));
+                    assert_eq!(root_key(&tree), Some(198));
+
+                    assert_eq!(tree.access(&0), Some(0));
+                    assert_eq!(root_key(&tree), Some(0));
+                    assert!(tree.validate().unwrap() < 100);
+
+                    assert_eq!(tree.access(&101), None);
+                    assert!([100, 102].contains(&root_key(&tree).unwrap()));
+
+                    assert_eq!(tree.delete(&50), Some(50));
+                    assert_eq!(tree.access(&50), None);
+                    assert!(tree.validate().is_ok());
+                }
+
+                #[test]
+                fn test_random_insert_delete() {
+                    check_random_insert_delete($tree::<i32, u64>::validate);
+                }
+            }
+        )*};
+    }
+
+    splay_tree_tests! {
+        array_backend: ArraySplayTree,
+        list_backend: ListSplayTree,
+    }
+}
@@ -0,0 +1,126 @@
+pub use crate::random::XorShift64;
+
+use crate::traits::{BulkLoadableTree, NodeIdentifiableTree, OrderedTree, Tree};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+pub fn check_random_insert_delete<T, Output, Error, F>(validate: F)
+where
+    T: Default + Tree<i32, u64>,
+    Error: Display,
+    F: Fn(&T) -> Result<Output, Error>,
+{
+    for seed in 1..=8 {
+        let mut random = XorShift64::new(seed);
+        let mut tree = T::default();
+        let mut model = BTreeMap::new();
+
+        for step in 0..3000 {
+            let key = random.next_below(256) as i32;
+
+            if random.next_below(5) < 2 {
+                assert_eq!(tree.delete(&key), model.remove(&key));
+            } else {
+                tree.insert(key, step);
+                model.insert(key, step);
+            }
+
+            if let Err(violation) = validate(&tree) {
+                panic!("seed {}, step {}: {}", seed, step, violation);
+            }
+        }
+
+        for key in 0..256 {
+            assert_eq!(tree.get(&key), model.get(&key).copied());
+        }
+
+        for key in model.keys().copied().collect::<Vec<_>>() {
+            assert_eq!(tree.delete(&key), model.remove(&key));
+            if let Err(violation) = validate(&tree) {
+                panic!("seed {}, deleting {}: {}", seed, key, violation);
+            }
+        }
+
+        assert!(validate(&tree).is_ok());
+    }
+}
+
+pub fn check_sequential_insert<T, Error, F>(validate: F) -> T
+where
+    T: Default + Tree<i32, u64>,
+    Error: Display,
+    F: Fn(&T) -> Result<usize, Error>,
+{
+    let mut tree = T::default();
+
+    for key in (0..1024).chain((1024..2048).rev()) {
+        tree.insert(key, key as u64 * 10);
+        if let Err(violation) = validate(&tree) {
+            panic!("inserting {}: {}", key, violation);
+        }
+    }
+
+    tree.insert(512, 0);
+    for key in 0..2048 {
+        assert_eq!(
+            tree.get(&key),
+            Some(if key == 512 { 0 } else { key as u64 * 10 })
+        );
+    }
+
+    tree
+}
+
+pub fn get_height<T, NodeId>(tree: &T, node_id: Option<NodeId>) -> usize
+where
+    T: NodeIdentifiableTree<i32, i32, NodeId>,
+    NodeId: Clone,
+{
+    node_id.map_or(0, |node_id| {
+        let left_height = get_height(tree, tree.get_left_son_id(node_id.clone()));
+        let right_height = get_height(tree, tree.get_right_son_id(node_id));
+        1 + left_height.max(right_height)
+    })
+}
+
+pub fn check_bulk_load<T, NodeId, F>(check_bst: F)
+where
+    T: Default + BulkLoadableTree<i32, i32> + NodeIdentifiableTree<i32, i32, NodeId>,
+    T: OrderedTree<i32, i32>,
+    NodeId: Clone,
+    F: Fn(&T) -> usize,
+{
+    for len in [0, 1, 2, 3, 7, 8, 100, 1023, 1024] {
+        let tree = T::from_sorted_iter((0..len).map(|key| (key, key * 10)));
+        let min_height = (len as usize + 1).next_power_of_two().ilog2() as usize;
+
+        assert_eq!(check_bst(&tree), len as usize);
+        assert_eq!(get_height(&tree, tree.get_root_id()), min_height);
+        for key in 0..len {
+            assert_eq!(Tree::get(&tree, &key), Some(key * 10));
+            assert_eq!(tree.select(key as usize), Some((key, key * 10)));
+        }
+    }
+
+    let mut tree = T::default();
+    for key in 0..500 {
+        tree.insert(key, -key);
+    }
+    assert_eq!(get_height(&tree, tree.get_root_id()), 500);
+
+    tree.rebuild_balanced();
+    assert_eq!(check_bst(&tree), 500);
+    assert_eq!(get_height(&tree, tree.get_root_id()), 9);
+
+    tree.insert(1000, 0);
+    assert_eq!(tree.delete(&250), Some(-250));
+    assert_eq!(tree.rank(&1000), 499);
+    assert_eq!(check_bst(&tree), 500);
+}
+
+pub fn check_unsorted_bulk_load<T>()
+where
+    T: BulkLoadableTree<i32, i32>,
+{
+    T::from_sorted_iter([(1, 1), (1, 2)]);
+}
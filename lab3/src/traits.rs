@@ -1,4 +1,10 @@
-use std::cell::{Ref, RefMut};
+use crate::guards::{GuardRef, GuardRefMut, Guards};
+use crate::observer::{Event, TreeObserver};
+use crate::traversal::{
+    InOrder, LendingIterator, LevelOrder, PostOrder, PreOrder, Traverse, TraverseMut,
+};
+use std::collections::BTreeMap;
+use std::ops::RangeBounds;
 
 pub trait Tree<Key, Value> {
     fn insert(&mut self, key: Key, val: Value);
@@ -6,33 +12,71 @@ pub trait Tree<Key, Value> {
     fn delete(&mut self, key: &Key) -> Option<Value>;
 }
 
+/// Lets `std::collections::BTreeMap` serve as the reference backend in tests and benchmarks.
+impl<Key, Value> Tree<Key, Value> for BTreeMap<Key, Value>
+where
+    Key: Ord,
+    Value: Clone,
+{
+    fn insert(&mut self, key: Key, val: Value) {
+        BTreeMap::insert(self, key, val);
+    }
+
+    fn get(&self, key: &Key) -> Option<Value> {
+        BTreeMap::get(self, key).cloned()
+    }
+
+    fn delete(&mut self, key: &Key) -> Option<Value> {
+        BTreeMap::remove(self, key)
+    }
+}
+
 pub trait NodeIdentifiableTree<Key, Value, NodeId> {
+    type Guards: Guards;
+
     fn insert_and_get_id(&mut self, key: Key, val: Value) -> NodeId;
+    fn get_id(&self, key: &Key) -> Option<NodeId>;
+    fn delete_by_id(&mut self, node_id: NodeId) -> Option<Value>;
 
-    fn get_by_id(&self, node_id: NodeId) -> Option<Ref<Value>>;
-    fn get_by_id_mut(&mut self, node_id: NodeId) -> Option<RefMut<Value>>;
+    fn get_key_by_id(&self, node_id: NodeId) -> Option<&Key>;
+    fn get_subtree_size(&self, node_id: NodeId) -> usize;
+    fn get_by_id(&self, node_id: NodeId) -> Option<GuardRef<'_, Self::Guards, Value>>;
+    fn get_by_id_mut(&mut self, node_id: NodeId) -> Option<GuardRefMut<'_, Self::Guards, Value>>;
+    fn get_entry_by_id_mut(
+        &mut self,
+        node_id: NodeId,
+    ) -> Option<(&Key, GuardRefMut<'_, Self::Guards, Value>)>;
 
     fn get_left_son_id(&self, node_id: NodeId) -> Option<NodeId>;
     fn get_right_son_id(&self, node_id: NodeId) -> Option<NodeId>;
     fn get_root_id(&self) -> Option<NodeId>;
 
-    fn get_left_son_mut(&mut self, node_id: NodeId) -> Option<RefMut<Value>>;
-    fn get_right_son_mut(&mut self, node_id: NodeId) -> Option<RefMut<Value>>;
-    fn get_root_mut(&mut self) -> Option<RefMut<Value>>;
+    fn get_left_son_mut(&mut self, node_id: NodeId) -> Option<GuardRefMut<'_, Self::Guards, Value>>;
+    fn get_right_son_mut(
+        &mut self,
+        node_id: NodeId,
+    ) -> Option<GuardRefMut<'_, Self::Guards, Value>>;
+    fn get_root_mut(&mut self) -> Option<GuardRefMut<'_, Self::Guards, Value>>;
 
     fn modify<F>(&mut self, node_id: NodeId, modifier: F)
     where
-        F: FnMut(RefMut<Value>);
+        F: FnMut(&mut Value);
 }
 
 pub trait ParentifiedTree<Key, Value, NodeId>: NodeIdentifiableTree<Key, Value, NodeId> {
     fn get_parent_id(&self, node_id: NodeId) -> Option<NodeId>;
-    fn get_parent(&mut self, node_id: NodeId) -> Option<Ref<Value>>;
-    fn get_parent_mut(&mut self, node_id: NodeId) -> Option<RefMut<Value>>;
+    fn get_parent(&mut self, node_id: NodeId) -> Option<GuardRef<'_, Self::Guards, Value>>;
+    fn get_parent_mut(&mut self, node_id: NodeId) -> Option<GuardRefMut<'_, Self::Guards, Value>>;
     fn get_left_uncle_id(&self, node_id: NodeId) -> Option<NodeId>;
     fn get_right_uncle_id(&self, node_id: NodeId) -> Option<NodeId>;
-    fn get_left_uncle_mut(&mut self, node_id: NodeId) -> Option<RefMut<Value>>;
-    fn get_right_uncle_mut(&mut self, node_id: NodeId) -> Option<RefMut<Value>>;
+    fn get_left_uncle_mut(
+        &mut self,
+        node_id: NodeId,
+    ) -> Option<GuardRefMut<'_, Self::Guards, Value>>;
+    fn get_right_uncle_mut(
+        &mut self,
+        node_id: NodeId,
+    ) -> Option<GuardRefMut<'_, Self::Guards, Value>>;
     fn is_left_son(&self, node_id: NodeId) -> bool;
     fn is_right_son(&self, node_id: NodeId) -> bool;
 }
@@ -41,3 +85,110 @@ pub trait RotatableTree<Key, Value, NodeId>: NodeIdentifiableTree<Key, Value, No
     fn right_rotate(&mut self, node_id: NodeId);
     fn left_rotate(&mut self, node_id: NodeId);
 }
+
+pub trait ObservableTree<Key, NodeId>: Sized {
+    type Observer: TreeObserver<Key>;
+
+    fn with_observer(observer: Self::Observer) -> Self;
+    fn observer(&self) -> &Self::Observer;
+    fn observer_mut(&mut self) -> &mut Self::Observer;
+    fn notify(&mut self, node_id: NodeId, event: Event);
+}
+
+pub trait OrderedTree<Key, Value>: Tree<Key, Value> {
+    fn min(&self) -> Option<(Key, Value)>;
+    fn max(&self) -> Option<(Key, Value)>;
+    fn floor(&self, key: &Key) -> Option<(Key, Value)>;
+    fn ceiling(&self, key: &Key) -> Option<(Key, Value)>;
+    fn successor(&self, key: &Key) -> Option<(Key, Value)>;
+    fn predecessor(&self, key: &Key) -> Option<(Key, Value)>;
+    fn range<R>(&self, range: R) -> impl Iterator<Item = (Key, Value)>
+    where
+        R: RangeBounds<Key>;
+    fn rank(&self, key: &Key) -> usize;
+    fn select(&self, rank: usize) -> Option<(Key, Value)>;
+}
+
+pub trait BulkLoadableTree<Key, Value>: Sized {
+    fn from_sorted_iter<I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = (Key, Value)>;
+    fn rebuild_balanced(&mut self);
+}
+
+pub trait TraversableTree<Key, Value, NodeId>: ParentifiedTree<Key, Value, NodeId> + Sized
+where
+    NodeId: Clone,
+{
+    fn in_order(&self) -> Traverse<'_, Key, Value, NodeId, Self, InOrder<NodeId>> {
+        Traverse::new(self)
+    }
+
+    fn pre_order(&self) -> Traverse<'_, Key, Value, NodeId, Self, PreOrder<NodeId>> {
+        Traverse::new(self)
+    }
+
+    fn post_order(&self) -> Traverse<'_, Key, Value, NodeId, Self, PostOrder<NodeId>> {
+        Traverse::new(self)
+    }
+
+    fn level_order(&self) -> Traverse<'_, Key, Value, NodeId, Self, LevelOrder<NodeId>> {
+        Traverse::new(self)
+    }
+
+    fn in_order_mut(&mut self) -> TraverseMut<'_, Key, Value, NodeId, Self, InOrder<NodeId>> {
+        TraverseMut::new(self)
+    }
+
+    fn in_order_for_each_mut<F>(&mut self, mut visitor: F)
+    where
+        F: FnMut(&Key, &mut Value),
+    {
+        let mut iter = self.in_order_mut();
+        while let Some((key, mut val)) = iter.next() {
+            visitor(key, &mut val);
+        }
+    }
+
+    fn pre_order_mut(&mut self) -> TraverseMut<'_, Key, Value, NodeId, Self, PreOrder<NodeId>> {
+        TraverseMut::new(self)
+    }
+
+    fn pre_order_for_each_mut<F>(&mut self, mut visitor: F)
+    where
+        F: FnMut(&Key, &mut Value),
+    {
+        let mut iter = self.pre_order_mut();
+        while let Some((key, mut val)) = iter.next() {
+            visitor(key, &mut val);
+        }
+    }
+
+    fn post_order_mut(&mut self) -> TraverseMut<'_, Key, Value, NodeId, Self, PostOrder<NodeId>> {
+        TraverseMut::new(self)
+    }
+
+    fn post_order_for_each_mut<F>(&mut self, mut visitor: F)
+    where
+        F: FnMut(&Key, &mut Value),
+    {
+        let mut iter = self.post_order_mut();
+        while let Some((key, mut val)) = iter.next() {
+            visitor(key, &mut val);
+        }
+    }
+
+    fn level_order_mut(&mut self) -> TraverseMut<'_, Key, Value, NodeId, Self, LevelOrder<NodeId>> {
+        TraverseMut::new(self)
+    }
+
+    fn level_order_for_each_mut<F>(&mut self, mut visitor: F)
+    where
+        F: FnMut(&Key, &mut Value),
+    {
+        let mut iter = self.level_order_mut();
+        while let Some((key, mut val)) = iter.next() {
+            visitor(key, &mut val);
+        }
+    }
+}
@@ -0,0 +1,403 @@
+use crate::ordered_tree::{get_max_id, get_min_id, get_next_id, get_prev_id};
+use crate::guards::{GuardRef, GuardRefMut};
+use crate::traits::ParentifiedTree;
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+pub trait TraversalOrder<Key, Value, NodeId, ImplTree> {
+    fn new(tree: &ImplTree) -> Self;
+    fn next_id(&mut self, tree: &ImplTree) -> Option<NodeId>;
+}
+
+pub trait DoubleEndedTraversalOrder<Key, Value, NodeId, ImplTree>:
+    TraversalOrder<Key, Value, NodeId, ImplTree>
+{
+    fn next_back_id(&mut self, tree: &ImplTree) -> Option<NodeId>;
+}
+
+/// Mutable traversals lend each entry until the next call, so the tree is never aliased.
+pub trait LendingIterator {
+    type Item<'a>
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+}
+
+pub trait DoubleEndedLendingIterator: LendingIterator {
+    fn next_back(&mut self) -> Option<Self::Item<'_>>;
+}
+
+pub struct InOrder<NodeId> {
+    front_id: Option<NodeId>,
+    back_id: Option<NodeId>,
+    remaining: usize,
+}
+
+impl<Key, Value, NodeId, ImplTree> TraversalOrder<Key, Value, NodeId, ImplTree> for InOrder<NodeId>
+where
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Value, NodeId>,
+{
+    fn new(tree: &ImplTree) -> Self {
+        let root_id = tree.get_root_id();
+
+        Self {
+            front_id: root_id.clone().map(|root_id| get_min_id(tree, root_id)),
+            back_id: root_id.clone().map(|root_id| get_max_id(tree, root_id)),
+            remaining: root_id.map_or(0, |root_id| tree.get_subtree_size(root_id)),
+        }
+    }
+
+    fn next_id(&mut self, tree: &ImplTree) -> Option<NodeId> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node_id = self.front_id.take()?;
+        self.front_id = get_next_id(tree, node_id.clone());
+        self.remaining -= 1;
+        Some(node_id)
+    }
+}
+
+impl<Key, Value, NodeId, ImplTree> DoubleEndedTraversalOrder<Key, Value, NodeId, ImplTree>
+    for InOrder<NodeId>
+where
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Value, NodeId>,
+{
+    fn next_back_id(&mut self, tree: &ImplTree) -> Option<NodeId> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node_id = self.back_id.take()?;
+        self.back_id = get_prev_id(tree, node_id.clone());
+        self.remaining -= 1;
+        Some(node_id)
+    }
+}
+
+pub(crate) fn collect_in_order_ids<Key, Value, NodeId, ImplTree>(tree: &ImplTree) -> Vec<NodeId>
+where
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Value, NodeId>,
+{
+    let mut order = <InOrder<NodeId> as TraversalOrder<Key, Value, NodeId, ImplTree>>::new(tree);
+    let mut node_ids = Vec::with_capacity(order.remaining);
+
+    while let Some(node_id) =
+        <InOrder<NodeId> as TraversalOrder<Key, Value, NodeId, ImplTree>>::next_id(&mut order, tree)
+    {
+        node_ids.push(node_id);
+    }
+
+    node_ids
+}
+
+pub struct PreOrder<NodeId> {
+    stack: Vec<NodeId>,
+}
+
+impl<Key, Value, NodeId, ImplTree> TraversalOrder<Key, Value, NodeId, ImplTree> for PreOrder<NodeId>
+where
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Value, NodeId>,
+{
+    fn new(tree: &ImplTree) -> Self {
+        Self {
+            stack: tree.get_root_id().into_iter().collect(),
+        }
+    }
+
+    fn next_id(&mut self, tree: &ImplTree) -> Option<NodeId> {
+        let node_id = self.stack.pop()?;
+        self.stack.extend(tree.get_right_son_id(node_id.clone()));
+        self.stack.extend(tree.get_left_son_id(node_id.clone()));
+        Some(node_id)
+    }
+}
+
+pub struct PostOrder<NodeId> {
+    stack: Vec<(NodeId, bool)>,
+}
+
+impl<Key, Value, NodeId, ImplTree> TraversalOrder<Key, Value, NodeId, ImplTree>
+    for PostOrder<NodeId>
+where
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Value, NodeId>,
+{
+    fn new(tree: &ImplTree) -> Self {
+        Self {
+            stack: tree
+                .get_root_id()
+                .map(|root_id| (root_id, false))
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    fn next_id(&mut self, tree: &ImplTree) -> Option<NodeId> {
+        loop {
+            let (node_id, sons_visited) = self.stack.pop()?;
+
+            if sons_visited {
+                return Some(node_id);
+            }
+
+            self.stack.push((node_id.clone(), true));
+            self.stack
+                .extend(tree.get_right_son_id(node_id.clone()).map(|id| (id, false)));
+            self.stack
+                .extend(tree.get_left_son_id(node_id).map(|id| (id, false)));
+        }
+    }
+}
+
+pub struct LevelOrder<NodeId> {
+    queue: VecDeque<NodeId>,
+}
+
+impl<Key, Value, NodeId, ImplTree> TraversalOrder<Key, Value, NodeId, ImplTree>
+    for LevelOrder<NodeId>
+where
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Value, NodeId>,
+{
+    fn new(tree: &ImplTree) -> Self {
+        Self {
+            queue: tree.get_root_id().into_iter().collect(),
+        }
+    }
+
+    fn next_id(&mut self, tree: &ImplTree) -> Option<NodeId> {
+        let node_id = self.queue.pop_front()?;
+        self.queue.extend(tree.get_left_son_id(node_id.clone()));
+        self.queue.extend(tree.get_right_son_id(node_id.clone()));
+        Some(node_id)
+    }
+}
+
+pub struct Traverse<'a, Key, Value, NodeId, ImplTree, Order> {
+    tree: &'a ImplTree,
+    order: Order,
+    _phantom: PhantomData<(&'a Key, &'a Value, NodeId)>,
+}
+
+impl<'a, Key, Value, NodeId, ImplTree, Order> Traverse<'a, Key, Value, NodeId, ImplTree, Order>
+where
+    Order: TraversalOrder<Key, Value, NodeId, ImplTree>,
+{
+    pub(crate) fn new(tree: &'a ImplTree) -> Self {
+        Self {
+            tree,
+            order: Order::new(tree),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, Key, Value, NodeId, ImplTree, Order> Iterator
+    for Traverse<'a, Key, Value, NodeId, ImplTree, Order>
+where
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Value, NodeId>,
+    Order: TraversalOrder<Key, Value, NodeId, ImplTree>,
+{
+    type Item = (&'a Key, GuardRef<'a, ImplTree::Guards, Value>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node_id = self.order.next_id(self.tree)?;
+        Some((
+            self.tree.get_key_by_id(node_id.clone())?,
+            self.tree.get_by_id(node_id)?,
+        ))
+    }
+}
+
+impl<Key, Value, NodeId, ImplTree, Order> DoubleEndedIterator
+    for Traverse<'_, Key, Value, NodeId, ImplTree, Order>
+where
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Value, NodeId>,
+    Order: DoubleEndedTraversalOrder<Key, Value, NodeId, ImplTree>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let node_id = self.order.next_back_id(self.tree)?;
+        Some((
+            self.tree.get_key_by_id(node_id.clone())?,
+            self.tree.get_by_id(node_id)?,
+        ))
+    }
+}
+
+pub struct TraverseMut<'a, Key, Value, NodeId, ImplTree, Order> {
+    tree: &'a mut ImplTree,
+    order: Order,
+    _phantom: PhantomData<(&'a Key, &'a Value, NodeId)>,
+}
+
+impl<'a, Key, Value, NodeId, ImplTree, Order> TraverseMut<'a, Key, Value, NodeId, ImplTree, Order>
+where
+    Order: TraversalOrder<Key, Value, NodeId, ImplTree>,
+{
+    pub(crate) fn new(tree: &'a mut ImplTree) -> Self {
+        Self {
+            order: Order::new(tree),
+            tree,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<Key, Value, NodeId, ImplTree, Order> LendingIterator
+    for TraverseMut<'_, Key, Value, NodeId, ImplTree, Order>
+where
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Value, NodeId>,
+    Order: TraversalOrder<Key, Value, NodeId, ImplTree>,
+{
+    type Item<'b>
+        = (&'b Key, GuardRefMut<'b, ImplTree::Guards, Value>)
+    where
+        Self: 'b;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        let node_id = self.order.next_id(self.tree)?;
+        self.tree.get_entry_by_id_mut(node_id)
+    }
+}
+
+impl<Key, Value, NodeId, ImplTree, Order> DoubleEndedLendingIterator
+    for TraverseMut<'_, Key, Value, NodeId, ImplTree, Order>
+where
+    NodeId: Clone,
+    ImplTree: ParentifiedTree<Key, Value, NodeId>,
+    Order: DoubleEndedTraversalOrder<Key, Value, NodeId, ImplTree>,
+{
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        let node_id = self.order.next_back_id(self.tree)?;
+        self.tree.get_entry_by_id_mut(node_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::array_bst::ArrayBST;
+    use crate::list_bst::ListBST;
+    use crate::test_utils::XorShift64;
+    use crate::traits::{TraversableTree, Tree};
+    use crate::traversal::{DoubleEndedLendingIterator, LendingIterator};
+    use crate::{ArrayRedBlackTree, ListRedBlackTree};
+    use std::collections::BTreeMap;
+
+    macro_rules! traversal_tests {
+        ($($backend:ident: $tree:ident,)*) => {$(
+            mod $backend {
+                use super::*;
+
+                #[test]
+                #[allow(unused_mut)]
+                fn test_orders() {
+                    let mut tree = $tree::<i32, i32>::default();
+                    for key in [4, 2, 6, 1, 3, 5, 7] {
+                        tree.insert(key, key * 10);
+                    }
+
+                    let keys = |iter: &mut dyn Iterator<Item = i32>| iter.collect::<Vec<_>>();
+                    assert_eq!(keys(&mut tree.in_order().map(|(key, _)| *key)), [1, 2, 3, 4, 5, 6, 7]);
+                    assert_eq!(keys(&mut tree.in_order().rev().map(|(key, _)| *key)), [7, 6, 5, 4, 3, 2, 1]);
+                    assert_eq!(keys(&mut tree.pre_order().map(|(key, _)| *key)), [4, 2, 1, 3, 6, 5, 7]);
+                    assert_eq!(keys(&mut tree.post_order().map(|(key, _)| *key)), [1, 3, 2, 5, 7, 6, 4]);
+                    assert_eq!(keys(&mut tree.level_order().map(|(key, _)| *key)), [4, 2, 6, 1, 3, 5, 7]);
+                    assert!(tree.in_order().all(|(key, val)| *val == key * 10));
+
+                    let mut iter = tree.in_order();
+                    assert_eq!(iter.next().map(|(key, _)| *key), Some(1));
+                    assert_eq!(iter.next_back().map(|(key, _)| *key), Some(7));
+                    assert_eq!(iter.next_back().map(|(key, _)| *key), Some(6));
+                    assert_eq!(iter.next().map(|(key, _)| *key), Some(2));
+                    assert_eq!(keys(&mut iter.map(|(key, _)| *key)), [3, 4, 5]);
+
+                    let mut visited = Vec::new();
+                    tree.pre_order_for_each_mut(|key, val| {
+                        visited.push(*key);
+                        *val += 1;
+                    });
+                    tree.post_order_for_each_mut(|key, _| visited.push(*key));
+                    assert_eq!(visited, [4, 2, 1, 3, 6, 5, 7, 1, 3, 2, 5, 7, 6, 4]);
+                    assert!(tree.in_order().all(|(key, val)| *val == key * 10 + 1));
+
+                    let mut iter = tree.in_order_mut();
+                    let mut ends = Vec::new();
+                    loop {
+                        match iter.next() {
+                            Some((key, mut val)) => {
+                                ends.push(*key);
+                                *val = -*val;
+                            }
+                            None => break,
+                        }
+                        if let Some((key, _)) = iter.next_back() {
+                            ends.push(*key);
+                        }
+                    }
+                    assert_eq!(ends, [1, 7, 2, 6, 3, 5, 4]);
+                    assert!(tree.in_order().all(|(key, val)| val.abs() == key * 10 + 1));
+                    assert_eq!(tree.in_order().filter(|(_, val)| **val < 0).count(), 4);
+
+                    let empty = $tree::<i32, i32>::default();
+                    assert_eq!(empty.in_order().count(), 0);
+                    assert_eq!(empty.pre_order().count(), 0);
+                    assert_eq!(empty.post_order().count(), 0);
+                    assert_eq!(empty.level_order().count(), 0);
+                }
+
+                #[test]
+                #[allow(unused_mut)]
+                fn test_mut_and_into_iter() {
+                    let mut random = XorShift64::new(0x7A5E);
+                    let mut tree = $tree::<i32, i32>::default();
+                    let mut model = BTreeMap::new();
+
+                    for _ in 0..200 {
+                        let key = random.next_below(100) as i32;
+                        let val = random.next_below(1000) as i32;
+                        tree.insert(key, val);
+                        model.insert(key, val);
+                    }
+
+                    let mut iter = tree.in_order_mut();
+                    while let Some((_, mut val)) = iter.next() {
+                        *val += 1;
+                    }
+                    let mut iter = tree.level_order_mut();
+                    while let Some((_, mut val)) = iter.next() {
+                        *val *= 2;
+                    }
+                    for val in model.values_mut() {
+                        *val = (*val + 1) * 2;
+                    }
+
+                    let mut entries = Vec::new();
+                    for (key, val) in &tree {
+                        entries.push((*key, *val));
+                    }
+                    assert_eq!(entries, model.clone().into_iter().collect::<Vec<_>>());
+                    assert_eq!(tree.pre_order().count(), model.len());
+                    assert_eq!(tree.post_order().count(), model.len());
+                    assert_eq!(tree.into_iter().collect::<Vec<_>>(), model.into_iter().collect::<Vec<_>>());
+                }
+            }
+        )*};
+    }
+
+    traversal_tests! {
+        array_bst: ArrayBST,
+        list_bst: ListBST,
+        array_red_black_tree: ArrayRedBlackTree,
+        list_red_black_tree: ListRedBlackTree,
+    }
+}
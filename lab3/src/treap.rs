@@ -0,0 +1,355 @@
+use crate::random::XorShift64;
+use crate::traits::{NodeIdentifiableTree, OrderedTree, ParentifiedTree, RotatableTree, Tree};
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
+
+const DEFAULT_SEED: u64 = 0x5EED_7EA9;
+
+#[derive(Clone)]
+pub struct Data<Value>
+where
+    Value: Clone,
+{
+    value: Value,
+    priority: u64,
+}
+
+impl<Value> std::fmt::Display for Data<Value>
+where
+    Value: Clone + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "[{} | p={}]", self.value, self.priority)
+    }
+}
+
+fn strip_priority<Key, Value>((key, data): (Key, Data<Value>)) -> (Key, Value)
+where
+    Value: Clone,
+{
+    (key, data.value)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Violation<NodeId> {
+    HeapOrderViolated(NodeId),
+    BrokenParentLink(NodeId),
+}
+
+impl<NodeId> std::fmt::Display for Violation<NodeId>
+where
+    NodeId: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::HeapOrderViolated(node_id) => {
+                write!(
+                    f,
+                    "node {:?} has a higher priority than its parent",
+                    node_id
+                )
+            }
+            Self::BrokenParentLink(node_id) => {
+                write!(f, "node {:?} has a wrong parent link", node_id)
+            }
+        }
+    }
+}
+
+pub struct Treap<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>,
+{
+    bst: ImplTree,
+    random: XorShift64,
+    _phantom_key: PhantomData<Key>,
+    _phantom_value: PhantomData<Value>,
+    _phantom_node_id: PhantomData<NodeId>,
+}
+
+impl<Key, Value, ImplTree, NodeId> Default for Treap<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>,
+{
+    #[inline(always)]
+    fn default() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> Treap<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>,
+{
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            bst: Default::default(),
+            random: XorShift64::new(seed),
+            _phantom_key: Default::default(),
+            _phantom_value: Default::default(),
+            _phantom_node_id: Default::default(),
+        }
+    }
+
+    fn get_priority(&self, node_id: Option<NodeId>) -> Option<u64> {
+        node_id.and_then(|node_id| self.bst.get_by_id(node_id).map(|data| data.priority))
+    }
+
+    fn sift_up(&mut self, node_id: NodeId) {
+        let priority = self.get_priority(Some(node_id.clone()));
+
+        while let Some(parent_id) = self.bst.get_parent_id(node_id.clone())
+            && self.get_priority(Some(parent_id.clone())) < priority
+        {
+            if self.bst.is_left_son(node_id.clone()) {
+                self.bst.right_rotate(parent_id);
+            } else {
+                self.bst.left_rotate(parent_id);
+            }
+        }
+    }
+
+    fn sift_down(&mut self, node_id: NodeId) {
+        while let Some(left_son_id) = self.bst.get_left_son_id(node_id.clone())
+            && let Some(right_son_id) = self.bst.get_right_son_id(node_id.clone())
+        {
+            if self.get_priority(Some(left_son_id)) > self.get_priority(Some(right_son_id)) {
+                self.bst.right_rotate(node_id.clone());
+            } else {
+                self.bst.left_rotate(node_id.clone());
+            }
+        }
+    }
+
+    pub fn validate(&self) -> Result<usize, Violation<NodeId>> {
+        let Some(root_id) = self.bst.get_root_id() else {
+            return Ok(0);
+        };
+
+        if self.bst.get_parent_id(root_id.clone()).is_some() {
+            return Err(Violation::BrokenParentLink(root_id));
+        }
+
+        self.validate_subtree(root_id)
+    }
+
+    fn validate_subtree(&self, node_id: NodeId) -> Result<usize, Violation<NodeId>> {
+        let priority = self.get_priority(Some(node_id.clone()));
+        let mut height = 0;
+
+        for son_id in [
+            self.bst.get_left_son_id(node_id.clone()),
+            self.bst.get_right_son_id(node_id.clone()),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if self.bst.get_parent_id(son_id.clone()).as_ref() != Some(&node_id) {
+                return Err(Violation::BrokenParentLink(son_id));
+            }
+
+            if self.get_priority(Some(son_id.clone())) > priority {
+                return Err(Violation::HeapOrderViolated(son_id));
+            }
+
+            height = height.max(self.validate_subtree(son_id)?);
+        }
+
+        Ok(height + 1)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> Tree<Key, Value> for Treap<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>,
+{
+    fn insert(&mut self, key: Key, val: Value) {
+        if let Some(node_id) = self.bst.get_id(&key) {
+            let priority = self.get_priority(Some(node_id)).expect("Node not found");
+            self.bst.insert_and_get_id(
+                key,
+                Data {
+                    value: val,
+                    priority,
+                },
+            );
+            return;
+        }
+
+        let priority = self.random.next_u64();
+        let node_id = self.bst.insert_and_get_id(
+            key,
+            Data {
+                value: val,
+                priority,
+            },
+        );
+        self.sift_up(node_id);
+    }
+
+    fn get(&self, key: &Key) -> Option<Value> {
+        self.bst.get(key).map(|x| x.value)
+    }
+
+    fn delete(&mut self, key: &Key) -> Option<Value> {
+        let node_id = self.bst.get_id(key)?;
+        self.sift_down(node_id.clone());
+
+        self.bst.delete_by_id(node_id).map(|data| data.value)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> OrderedTree<Key, Value> for Treap<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord + Clone,
+    Value: Clone,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + OrderedTree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>,
+{
+    fn min(&self) -> Option<(Key, Value)> {
+        self.bst.min().map(strip_priority)
+    }
+
+    fn max(&self) -> Option<(Key, Value)> {
+        self.bst.max().map(strip_priority)
+    }
+
+    fn floor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.floor(key).map(strip_priority)
+    }
+
+    fn ceiling(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.ceiling(key).map(strip_priority)
+    }
+
+    fn successor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.successor(key).map(strip_priority)
+    }
+
+    fn predecessor(&self, key: &Key) -> Option<(Key, Value)> {
+        self.bst.predecessor(key).map(strip_priority)
+    }
+
+    fn range<R>(&self, range: R) -> impl Iterator<Item = (Key, Value)>
+    where
+        R: RangeBounds<Key>,
+    {
+        self.bst.range(range).map(strip_priority)
+    }
+
+    fn rank(&self, key: &Key) -> usize {
+        self.bst.rank(key)
+    }
+
+    fn select(&self, rank: usize) -> Option<(Key, Value)> {
+        self.bst.select(rank).map(strip_priority)
+    }
+}
+
+impl<Key, Value, ImplTree, NodeId> std::fmt::Display for Treap<Key, Value, ImplTree, NodeId>
+where
+    Key: Ord + std::fmt::Display,
+    Value: Clone + std::fmt::Display,
+    NodeId: Default + Clone + PartialEq + Eq,
+    ImplTree: Default
+        + Tree<Key, Data<Value>>
+        + NodeIdentifiableTree<Key, Data<Value>, NodeId>
+        + ParentifiedTree<Key, Data<Value>, NodeId>
+        + RotatableTree<Key, Data<Value>, NodeId>
+        + std::fmt::Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.bst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{check_random_insert_delete, check_sequential_insert};
+    use crate::traits::{NodeIdentifiableTree, Tree};
+    use crate::{ArrayTreap, ListTreap};
+
+    macro_rules! treap_tests {
+        ($($backend:ident: $tree:ident,)*) => {$(
+            mod $backend {
+                use super::*;
+
+                #[test]
+                fn test_insert() {
+                    let tree = check_sequential_insert($tree::<i32, u64>::validate);
+                    let height = tree.validate().unwrap();
+                    assert!((11..=40).contains(&height));
+                }
+
+                #[test]
+                fn test_seeded_shape() {
+                    let build = |seed| {
+                        let mut tree = $tree::<i32, i32>::with_seed(seed);
+                        for key in 0..64 {
+                            tree.insert(key, key);
+                        }
+                        tree
+                    };
+
+                    let (first, second, other) = (build(7), build(7), build(8));
+                    let root_key = |tree: &$tree<i32, i32>| {
+                        tree.bst.get_key_by_id(tree.bst.get_root_id().unwrap()).copied()
+                    };
+
+                    assert_eq!(first.validate(), second.validate());
+                    assert_eq!(root_key(&first), root_key(&second));
+                    assert_ne!(
+                        (root_key(&first), first.validate()),
+                        (root_key(&other), other.validate())
+                    );
+                }
+
+                #[test]
+                fn test_random_insert_delete() {
+                    check_random_insert_delete($tree::<i32, u64>::validate);
+                }
+            }
+        )*};
+    }
+
+    treap_tests! {
+        array_backend: ArrayTreap,
+        list_backend: ListTreap,
+    }
+}
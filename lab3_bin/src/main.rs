@@ -0,0 +1,137 @@
+use lab3::random::XorShift64;
+use lab3::traits::{OrderedTree, Tree};
+use lab3::{ArrayAvlTree, ArrayRedBlackTree, ArraySplayTree, ArrayTreap, benchmark_suite};
+use std::fs::File;
+use std::io;
+
+fn run_benchmarks(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut size = benchmark_suite::BIG_TREE_SIZE;
+    let mut output_path = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => {
+                size = args
+                    .next()
+                    .ok_or("--size expects a number")?
+                    .parse()
+                    .map_err(|error| format!("failed to parse --size: {}", error))?;
+            }
+            "--output" => output_path = Some(args.next().ok_or("--output expects a file")?),
+            other => {
+                return Err(format!(
+                    "unknown option \"{}\", expected --size <n> or --output <file>",
+                    other
+                ));
+            }
+        }
+    }
+
+    let min_time = benchmark_suite::MIN_MEASUREMENT_TIME;
+    let result = match output_path {
+        Some(path) => {
+            let mut file = File::create(&path)
+                .map_err(|error| format!("cannot create {}: {}", path, error))?;
+            benchmark_suite::run(size, min_time, &mut file)
+        }
+        None => benchmark_suite::run(size, min_time, &mut io::stdout()),
+    };
+
+    result.map_err(|error| error.to_string())
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    match args.next().as_deref() {
+        None => {}
+        Some("--benchmark") => {
+            if let Err(error) = run_benchmarks(args) {
+                eprintln!("error: {}", error);
+                std::process::exit(1);
+            }
+            return;
+        }
+        Some(other) => {
+            eprintln!("error: unknown option \"{}\", expected --benchmark", other);
+            std::process::exit(1);
+        }
+    }
+
+    let mut tree = ArrayRedBlackTree::<i32, i32>::default();
+    tree.insert(15, 0);
+    tree.insert(5, 1);
+    tree.insert(1, 2);
+
+    println!("{}", tree);
+    println!("min = {:?}, max = {:?}", tree.min(), tree.max());
+    println!(
+        "range 2..=15 = {:?}",
+        tree.range(2..=15).collect::<Vec<_>>()
+    );
+    println!(
+        "floor(4) = {:?}, ceiling(4) = {:?}, predecessor(5) = {:?}, successor(5) = {:?}",
+        tree.floor(&4),
+        tree.ceiling(&4),
+        tree.predecessor(&5),
+        tree.successor(&5)
+    );
+    println!(
+        "rank(5) = {}, select(1) = {:?}",
+        tree.rank(&5),
+        tree.select(1)
+    );
+    println!(
+        "pre-order = {:?}, level-order = {:?}",
+        tree.pre_order().map(|(key, _)| *key).collect::<Vec<_>>(),
+        tree.level_order().map(|(key, _)| *key).collect::<Vec<_>>()
+    );
+
+    let mut random = XorShift64::new(42);
+    let mut red_black_tree = ArrayRedBlackTree::<u64, u64>::default();
+    let mut avl_tree = ArrayAvlTree::<u64, u64>::default();
+    let mut treap = ArrayTreap::<u64, u64>::with_seed(42);
+    let mut splay_tree = ArraySplayTree::<u64, u64>::default();
+    for _ in 0..1000 {
+        let key = random.next_below(10_000);
+        red_black_tree.insert(key, key);
+        avl_tree.insert(key, key);
+        treap.insert(key, key);
+        splay_tree.insert(key, key);
+    }
+    println!(
+        "black height = {:?}, avl height = {:?}, treap height = {:?}, splay height = {:?}",
+        red_black_tree.validate(),
+        avl_tree.validate(),
+        treap.validate(),
+        splay_tree.validate()
+    );
+
+    // {
+    //     let mut tree = ArrayBST::default();
+    //     tree.insert(1000, 0);
+    //     let id = tree.insert_and_get_id(5, 0);
+    //     tree.insert(4, 0);
+    //     tree.insert(7, 0);
+    //     tree.insert(6, 0);
+    //     tree.insert(8, 0);
+    //
+    //     println!("{}", tree);
+    //
+    //     tree.left_rotate(id);
+    //     println!("Left rotated:\n {}", tree);
+    // }
+    // {
+    //     let mut tree = ArrayBST::default();
+    //     tree.insert(1000, 0);
+    //     let id = tree.insert_and_get_id(5, 0);
+    //     tree.insert(3, 0);
+    //     tree.insert(1, 0);
+    //     tree.insert(4, 0);
+    //     tree.insert(6, 0);
+    //
+    //     println!("{}", tree);
+    //
+    //     tree.right_rotate(id);
+    //     println!("Left rotated:\n {}", tree);
+    // }
+}